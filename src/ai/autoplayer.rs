@@ -0,0 +1,274 @@
+use crate::{
+    ai::evaluation::Evaluator,
+    gameplay::{
+        board::{Board, TakesTile},
+        game::{Game, Observer, Over, ProcessRows, TileFloating, TileNeeded},
+        placement::{placements, placements_from, Move, Placement},
+        spawn::SpawnPolicy,
+    },
+    geometry::tile::BasicTile,
+};
+use either::Either;
+
+/// Maximum number of preview tiles an [`Autoplayer`] takes into account.
+///
+/// Each additional tile of lookahead multiplies the number of evaluated boards by the number of
+/// placements per tile, so keep this small.
+pub const MAX_LOOKAHEAD: usize = 2;
+
+/// Player choosing placements by means of an [`Evaluator`].
+#[derive(Clone, Debug)]
+pub struct Autoplayer<E> {
+    evaluator: E,
+    lookahead: usize,
+}
+
+impl<E> Autoplayer<E>
+where
+    E: Evaluator,
+{
+    /// Create a player without lookahead.
+    pub fn new(evaluator: E) -> Self {
+        Self {
+            evaluator,
+            lookahead: 0,
+        }
+    }
+
+    /// Take the next `lookahead` preview tiles into account, capped at [`MAX_LOOKAHEAD`].
+    #[must_use]
+    pub fn with_lookahead(self, lookahead: usize) -> Self {
+        Self {
+            lookahead: lookahead.min(MAX_LOOKAHEAD),
+            ..self
+        }
+    }
+
+    /// Choose the placement of `tile` on `board` leading to the best evaluated board.
+    ///
+    /// `preview` holds the tiles to be placed after `tile` in order. Only the first few of them
+    /// are taken into account, see [`Autoplayer::with_lookahead`].
+    ///
+    /// Tiles are assumed to spawn according to [`SpawnPolicy::default`].
+    ///
+    /// Returns `None` if `tile` cannot be placed at all.
+    pub fn choose_placement(
+        &self,
+        board: &Board<TakesTile>,
+        tile: BasicTile,
+        preview: &[BasicTile],
+    ) -> Option<Placement> {
+        let spawn = SpawnPolicy::default().spawn(board, tile);
+        self.choose_placement_from(board, &spawn, preview)
+    }
+
+    /// Like [`Autoplayer::choose_placement`], but for the tile spawned at `spawn`.
+    ///
+    /// Tiles in `preview` are still assumed to spawn according to [`SpawnPolicy::default`].
+    pub fn choose_placement_from(
+        &self,
+        board: &Board<TakesTile>,
+        spawn: &Placement,
+        preview: &[BasicTile],
+    ) -> Option<Placement> {
+        let preview = &preview[..preview.len().min(self.lookahead)];
+
+        placements_from(board, spawn)
+            .map(|placement| {
+                let (next, cleared_rows) = drop_tile(board, &placement);
+                (self.score(&next, cleared_rows, preview), placement)
+            })
+            // on ties, prefer the first placement
+            .fold(
+                None,
+                |best: Option<(i32, Placement)>, candidate| match best {
+                    Some(best) if best.0 >= candidate.0 => Some(best),
+                    _ => Some(candidate),
+                },
+            )
+            .map(|(_, placement)| placement)
+    }
+
+    fn score(&self, board: &Board<TakesTile>, cleared_rows: usize, preview: &[BasicTile]) -> i32 {
+        match preview.split_first() {
+            None => self.evaluator.evaluate(board, cleared_rows),
+            Some((tile, preview)) => placements(board, tile.clone())
+                .map(|placement| {
+                    let (next, cleared) = drop_tile(board, &placement);
+                    self.score(&next, cleared_rows + cleared, preview)
+                })
+                .max()
+                // the game is over, which is the worst possible outcome
+                .unwrap_or(i32::MIN),
+        }
+    }
+
+    /// Play `game` using the tiles produced by `tiles` until either the game is over or `tiles`
+    /// is exhausted.
+    pub fn play<O, I>(
+        &self,
+        mut game: Game<TileNeeded, O>,
+        tiles: I,
+    ) -> Either<Game<TileNeeded, O>, Game<Over, O>>
+    where
+        O: Observer,
+        I: IntoIterator<Item = BasicTile>,
+    {
+        let mut tiles = tiles.into_iter();
+        let mut queue = heapless::Vec::<BasicTile, { MAX_LOOKAHEAD + 1 }>::new();
+
+        loop {
+            while queue.len() <= self.lookahead {
+                let Some(tile) = tiles.next() else {
+                    break;
+                };
+                queue.push(tile).expect("Queue is limited by the lookahead");
+            }
+
+            if queue.is_empty() {
+                return Either::Left(game);
+            }
+            let tile = queue.remove(0);
+
+            game = match self.play_tile(game, tile, &queue) {
+                Either::Left(game) => game,
+                Either::Right(game) => return Either::Right(game),
+            };
+        }
+    }
+
+    /// Place `tile`, move it to the best placement and drop it.
+    pub fn play_tile<O>(
+        &self,
+        game: Game<TileNeeded, O>,
+        tile: BasicTile,
+        preview: &[BasicTile],
+    ) -> Either<Game<TileNeeded, O>, Game<Over, O>>
+    where
+        O: Observer,
+    {
+        let game = match game.place_tile(tile) {
+            Either::Left(game) => game,
+            Either::Right(game) => return Either::Right(game),
+        };
+
+        let path = self
+            .choose_placement_from(game.board(), game.tile(), preview)
+            .and_then(|target| game.path_to(&target))
+            .unwrap_or_default();
+
+        let mut game = follow_path(game, &path);
+        loop {
+            game = match game.process_row() {
+                Either::Left(game) => game,
+                Either::Right(game) => return Either::Left(game),
+            }
+        }
+    }
+}
+
+/// Move the floating tile along `path` and drop it afterwards.
+fn follow_path<O>(mut game: Game<TileFloating, O>, path: &[Move]) -> Game<ProcessRows, O>
+where
+    O: Observer,
+{
+    for m in path {
+        let res = match m {
+            Move::Left => game.move_tile_left(),
+            Move::Right => game.move_tile_right(),
+            Move::Rotate => game.rotate_tile(),
+            Move::Descend => {
+                game = match game.descend_tile() {
+                    Either::Left(game) => game,
+                    Either::Right(game) => return game,
+                };
+                Ok(())
+            }
+        };
+        res.expect("Path should consist of valid moves");
+    }
+
+    loop {
+        game = match game.descend_tile() {
+            Either::Left(game) => game,
+            Either::Right(game) => return game,
+        }
+    }
+}
+
+/// Freeze `placement` on `board` and process all rows, returning the resulting board and the
+/// number of cleared rows.
+fn drop_tile(board: &Board<TakesTile>, placement: &Placement) -> (Board<TakesTile>, usize) {
+    let (board, report) = board
+        .clone()
+        .freeze_tile_assume_valid(placement.clone())
+        .process_rows_instantly();
+    (board, report.count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ai::evaluation::Weights,
+        gameplay::game::NoopObserver,
+        geometry::tile::{Displacee, RotatedTile},
+    };
+
+    const TILES: [BasicTile; 3] = [BasicTile::Line, BasicTile::Square, BasicTile::Diagonal];
+
+    #[test]
+    fn prefers_clearing_rows() {
+        // Bottom row misses its rightmost cell
+        let mut board = Board::new();
+        for col in 1..=4 {
+            board = drop_tile(
+                &board,
+                &RotatedTile::new(BasicTile::Square).displace_by(col, 1),
+            )
+            .0;
+        }
+
+        let player = Autoplayer::new(Weights::default());
+        let placement = player
+            .choose_placement(&board, BasicTile::Square, &[])
+            .unwrap();
+
+        assert_eq!(*placement.displ_x(), 5);
+        assert_eq!(*placement.displ_y(), 1);
+    }
+
+    #[test]
+    fn drop_tile_counts_cleared_rows() {
+        let mut board = Board::new();
+        for col in 1..=4 {
+            let (next, cleared_rows) = drop_tile(
+                &board,
+                &RotatedTile::new(BasicTile::Line).displace_by(col, 1),
+            );
+            assert_eq!(cleared_rows, 0);
+            board = next;
+        }
+
+        let (board, cleared_rows) =
+            drop_tile(&board, &RotatedTile::new(BasicTile::Line).displace_by(5, 1));
+        assert_eq!(cleared_rows, 2);
+        assert!(board.last_clear().is_perfect_clear());
+    }
+
+    #[test]
+    fn plays_game() {
+        let player = Autoplayer::new(Weights::default());
+        let tiles = TILES.iter().cycle().take(60).cloned();
+
+        assert!(player.play(Game::<_, NoopObserver>::new(), tiles).is_left());
+    }
+
+    #[test]
+    fn plays_game_with_lookahead() {
+        let player = Autoplayer::new(Weights::default()).with_lookahead(1);
+        let tiles = TILES.iter().cycle().take(30).cloned();
+
+        assert!(player.play(Game::<_, NoopObserver>::new(), tiles).is_left());
+    }
+}
@@ -0,0 +1,80 @@
+use crate::gameplay::board::{Board, TakesTile};
+
+/// Scoring of boards resulting from placing tiles.
+pub trait Evaluator {
+    /// Score `board`, which has been reached by clearing `cleared_rows` rows.
+    ///
+    /// Higher scores denote better boards.
+    fn evaluate(&self, board: &Board<TakesTile>, cleared_rows: usize) -> i32;
+}
+
+/// Weighted sum of board features.
+///
+/// Features that should be avoided (like holes) are to be weighted negatively, features that
+/// should be strived for (like cleared rows) are to be weighted positively.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Weights {
+    /// Weight of the sum of all column heights
+    pub aggregate_height: i32,
+    /// Weight of the number of empty cells covered by an occupied cell in the same column
+    pub holes: i32,
+    /// Weight of the sum of absolute height differences between neighbouring columns
+    pub bumpiness: i32,
+    /// Weight of the number of rows cleared on the way to the board
+    pub cleared_rows: i32,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Self {
+            aggregate_height: -51,
+            holes: -36,
+            bumpiness: -18,
+            cleared_rows: 76,
+        }
+    }
+}
+
+fn weighted(weight: i32, feature: usize) -> i32 {
+    weight.saturating_mul(feature.try_into().unwrap_or(i32::MAX))
+}
+
+impl Evaluator for Weights {
+    fn evaluate(&self, board: &Board<TakesTile>, cleared_rows: usize) -> i32 {
+        weighted(self.aggregate_height, board.aggregate_height())
+            .saturating_add(weighted(self.holes, board.holes()))
+            .saturating_add(weighted(self.bumpiness, board.bumpiness()))
+            .saturating_add(weighted(self.cleared_rows, cleared_rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::tile::{BasicTile, DisplacedTile, Displacee, RotatedTile};
+
+    #[test]
+    fn empty_board_scores_zero() {
+        assert_eq!(Weights::default().evaluate(&Board::new(), 0), 0);
+    }
+
+    #[test]
+    fn features_are_weighted() {
+        // Diagonal in the bottom left corner, covering a single hole
+        let board = Board::new()
+            .freeze_tile_assume_valid(
+                DisplacedTile::new(RotatedTile::new(BasicTile::Diagonal)).displace_by(1, 1),
+            )
+            .process_all_rows()
+            .0;
+        let weights = Weights {
+            aggregate_height: 1,
+            holes: 10,
+            bumpiness: 100,
+            cleared_rows: 1000,
+        };
+
+        // heights: [1, 2, 0, 0, 0], holes: 1, bumpiness: 1 + 2
+        assert_eq!(weights.evaluate(&board, 2), 3 + 10 + 300 + 2000);
+    }
+}
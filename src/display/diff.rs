@@ -0,0 +1,206 @@
+use core::cell::RefCell;
+
+use crate::{
+    display::sink::{DisplaySink, Frame},
+    gameplay::game::Observer,
+    geometry::grid::Grid,
+};
+
+/// Elements of a single layer changed between two frames.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LayerDiff {
+    /// Elements turned on
+    pub set: Grid,
+    /// Elements turned off
+    pub cleared: Grid,
+}
+
+impl LayerDiff {
+    fn between(previous: &Grid, current: &Grid) -> Self {
+        Self {
+            set: current - previous,
+            cleared: previous - current,
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty() && self.cleared.is_empty()
+    }
+}
+
+/// Change from the previously shown frame to `frame`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FrameDiff {
+    /// The frame to show
+    pub frame: Frame,
+    pub active: LayerDiff,
+    pub passive: LayerDiff,
+}
+
+impl FrameDiff {
+    #[must_use]
+    pub fn between(previous: &Frame, current: Frame) -> Self {
+        Self {
+            active: LayerDiff::between(&previous.active, &current.active),
+            passive: LayerDiff::between(&previous.passive, &current.passive),
+            frame: current,
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty() && self.passive.is_empty()
+    }
+
+    /// Change of both layers merged, see [`Frame::combined`].
+    ///
+    /// Elements moving from one layer to the other are not part of the combined change.
+    #[must_use]
+    pub fn combined(&self, previous: &Frame) -> LayerDiff {
+        LayerDiff::between(&previous.combined(), &self.frame.combined())
+    }
+}
+
+/// Destination of the changes between consecutive frames, e.g. a slow serial or e-paper display.
+pub trait DiffSink {
+    fn show_diff(&self, diff: &FrameDiff);
+}
+
+impl<D> DiffSink for &D
+where
+    D: DiffSink + ?Sized,
+{
+    fn show_diff(&self, diff: &FrameDiff) {
+        (**self).show_diff(diff);
+    }
+}
+
+/// [`Observer`] and [`DisplaySink`] adapter only forwarding frames which differ from the
+/// previous one, along with what changed.
+///
+/// The display is assumed to be blank initially, so the first frame is diffed against an empty
+/// frame.
+#[derive(Debug, Default)]
+pub struct DiffingObserver<D> {
+    sink: D,
+    previous: RefCell<Frame>,
+}
+
+impl<D> DiffingObserver<D>
+where
+    D: DiffSink,
+{
+    pub fn new(sink: D) -> Self {
+        Self {
+            sink,
+            previous: RefCell::new(Frame::default()),
+        }
+    }
+
+    pub fn sink(&self) -> &D {
+        &self.sink
+    }
+
+    /// Frame forwarded last
+    #[must_use]
+    pub fn previous(&self) -> Frame {
+        self.previous.borrow().clone()
+    }
+
+    /// Diff the next frame against an empty frame, e.g. after the display has been cleared.
+    pub fn reset(&self) {
+        *self.previous.borrow_mut() = Frame::default();
+    }
+
+    pub fn into_inner(self) -> D {
+        self.sink
+    }
+}
+
+impl<D> DisplaySink for DiffingObserver<D>
+where
+    D: DiffSink,
+{
+    fn show(&self, frame: Frame) {
+        let diff = FrameDiff::between(&self.previous.borrow(), frame);
+        if diff.is_empty() {
+            return;
+        }
+
+        self.previous.borrow_mut().clone_from(&diff.frame);
+        self.sink.show_diff(&diff);
+    }
+}
+
+impl<D> Observer for DiffingObserver<D>
+where
+    D: DiffSink,
+{
+    fn signal_board_changed(&self, active: Grid, passive: Grid) {
+        self.show(Frame::new(active, passive));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder(RefCell<heapless::Vec<FrameDiff, 8>>);
+
+    impl DiffSink for Recorder {
+        fn show_diff(&self, diff: &FrameDiff) {
+            self.0.borrow_mut().push(diff.clone()).unwrap();
+        }
+    }
+
+    #[test]
+    fn suppresses_identical_frames() {
+        let observer = DiffingObserver::new(Recorder::default());
+
+        observer.signal_board_changed(Grid::default(), Grid::default());
+        observer.signal_board_changed(Grid::R4, Grid::R0);
+        observer.signal_board_changed(Grid::R4, Grid::R0);
+        observer.signal_board_changed(Grid::R3, Grid::R0);
+
+        let diffs = observer.sink().0.borrow();
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(diffs[0].active.set, Grid::R4);
+        assert_eq!(diffs[0].passive.set, Grid::R0);
+        assert!(diffs[0].active.cleared.is_empty());
+
+        assert_eq!(
+            diffs[1].active,
+            LayerDiff {
+                set: Grid::R3,
+                cleared: Grid::R4,
+            }
+        );
+        assert!(diffs[1].passive.is_empty());
+        assert_eq!(diffs[1].frame, observer.previous());
+    }
+
+    #[test]
+    fn reset_redraws() {
+        let observer = DiffingObserver::new(Recorder::default());
+
+        observer.signal_board_changed(Grid::R4, Grid::R0);
+        observer.reset();
+        observer.signal_board_changed(Grid::R4, Grid::R0);
+
+        let diffs = observer.sink().0.borrow();
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(diffs[0], diffs[1]);
+    }
+
+    #[test]
+    fn combined_diff() {
+        let previous = Frame::new(Grid::R4, Grid::R0);
+        let diff = FrameDiff::between(&previous, Frame::new(Grid::default(), Grid::R0 | Grid::R4));
+
+        assert!(!diff.is_empty());
+        assert!(diff.combined(&previous).set.is_empty());
+        assert!(diff.combined(&previous).cleared.is_empty());
+    }
+}
@@ -0,0 +1,336 @@
+use crate::geometry::grid::Grid;
+
+/// Number of brightness levels above off, see [`BrightnessGrid`].
+pub const MAX_BRIGHTNESS: u8 = 9;
+
+/// Maximum number of matrix rows of any [`Revision`].
+pub const MAX_MATRIX_ROWS: usize = 5;
+
+/// Matrix `(row, col)` driving each LED, top row of LEDs first, leftmost LED first.
+///
+/// Rows are numbered `ROW1`..`ROW3` and columns `COL1`..`COL9` in the v1 schematics, 1-indexed.
+const V1_LAYOUT: [[(usize, usize); Grid::NUM_COLS]; Grid::NUM_ROWS] = [
+    [(0, 0), (1, 3), (0, 1), (1, 4), (0, 2)],
+    [(2, 3), (2, 4), (2, 5), (2, 6), (2, 7)],
+    [(1, 1), (0, 8), (1, 2), (2, 8), (1, 0)],
+    [(0, 7), (0, 6), (0, 5), (0, 4), (0, 3)],
+    [(2, 2), (1, 6), (2, 0), (1, 5), (2, 1)],
+];
+
+/// Hardware revision of the micro:bit, determining how its LEDs are wired.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Revision {
+    /// 5x5 LEDs multiplexed as a 3 by 9 matrix
+    V1,
+    /// 5x5 LEDs wired to 5 row and 5 column pins directly
+    V2,
+}
+
+impl Revision {
+    /// Number of row pins, i.e. rows scanned per frame
+    #[must_use]
+    pub fn matrix_rows(self) -> usize {
+        match self {
+            Self::V1 => 3,
+            Self::V2 => 5,
+        }
+    }
+
+    /// Number of column pins
+    #[must_use]
+    pub fn matrix_cols(self) -> usize {
+        match self {
+            Self::V1 => 9,
+            Self::V2 => 5,
+        }
+    }
+
+    /// Matrix `(row, col)` driving the LED showing the grid's element `(row, col)`.
+    ///
+    /// Matrix rows and columns are 0-indexed, the grid's bottom row being the LEDs' bottom row.
+    #[must_use]
+    pub fn matrix_position(self, row: usize, col: usize) -> Option<(usize, usize)> {
+        if row >= Grid::NUM_ROWS || col >= Grid::NUM_COLS {
+            return None;
+        }
+
+        // LEDs are numbered from the top
+        let led_row = Grid::NUM_ROWS - 1 - row;
+        match self {
+            Self::V1 => Some(V1_LAYOUT[led_row][col]),
+            Self::V2 => Some((led_row, col)),
+        }
+    }
+
+    /// Pin patterns lighting up `grid`'s elements when scanned row by row.
+    #[must_use]
+    pub fn scan(self, grid: &Grid) -> ScanFrame {
+        let mut cols = [0; MAX_MATRIX_ROWS];
+
+        for (row, col) in grid.cells() {
+            let (matrix_row, matrix_col) = self
+                .matrix_position(row, col)
+                .expect("Grid cells should be within the grid");
+            cols[matrix_row] |= 1 << matrix_col;
+        }
+
+        ScanFrame {
+            revision: self,
+            cols,
+        }
+    }
+
+    /// PWM sub-frames showing `grid`, see [`BrightnessGrid::sub_frame`].
+    ///
+    /// The sub-frames are meant to be shown for equally long time slices each, one after the
+    /// other, so that an LED of brightness `b` is lit for `b` out of [`MAX_BRIGHTNESS`] slices.
+    pub fn sub_frames(self, grid: &BrightnessGrid) -> impl Iterator<Item = ScanFrame> + '_ {
+        (0..MAX_BRIGHTNESS).map(move |slice| self.scan(&grid.sub_frame(slice)))
+    }
+}
+
+/// Pin patterns of a single matrix row.
+///
+/// Row pins are active high and column pins active low on both revisions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RowPattern {
+    row: usize,
+    cols: u16,
+    col_mask: u16,
+}
+
+impl RowPattern {
+    /// 0-indexed matrix row this pattern is shown on
+    #[must_use]
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Columns to light, bit `col` being set iff matrix column `col`'s LED is on
+    #[must_use]
+    pub fn cols(&self) -> u16 {
+        self.cols
+    }
+
+    /// Levels of the row pins, bit `row` being set iff the pin is driven high
+    #[must_use]
+    pub fn row_pins(&self) -> u16 {
+        1 << self.row
+    }
+
+    /// Levels of the column pins, bit `col` being set iff the pin is driven high
+    #[must_use]
+    pub fn col_pins(&self) -> u16 {
+        !self.cols & self.col_mask
+    }
+}
+
+/// Pin patterns of all matrix rows making up a single frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScanFrame {
+    revision: Revision,
+    cols: [u16; MAX_MATRIX_ROWS],
+}
+
+impl ScanFrame {
+    #[must_use]
+    pub fn revision(&self) -> Revision {
+        self.revision
+    }
+
+    /// Patterns to show one after another, first matrix row first
+    pub fn rows(&self) -> impl Iterator<Item = RowPattern> + '_ {
+        let col_mask = (1 << self.revision.matrix_cols()) - 1;
+
+        self.cols[..self.revision.matrix_rows()]
+            .iter()
+            .enumerate()
+            .map(move |(row, cols)| RowPattern {
+                row,
+                cols: *cols,
+                col_mask,
+            })
+    }
+}
+
+/// 5 by 5 grid of brightness levels in `0..=MAX_BRIGHTNESS`, indexed like [`Grid`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BrightnessGrid([[u8; Grid::NUM_COLS]; Grid::NUM_ROWS]);
+
+impl BrightnessGrid {
+    /// Grid of `levels`, bottom row first, where levels above [`MAX_BRIGHTNESS`] are capped.
+    #[must_use]
+    pub fn new(levels: [[u8; Grid::NUM_COLS]; Grid::NUM_ROWS]) -> Self {
+        Self(levels.map(|row| row.map(|level| level.min(MAX_BRIGHTNESS))))
+    }
+
+    /// Brightness of element `(row, col)`, `None` if out of range
+    #[must_use]
+    pub fn level(&self, row: usize, col: usize) -> Option<u8> {
+        self.0.get(row).and_then(|levels| levels.get(col)).copied()
+    }
+
+    /// Set element `(row, col)` to `level`, capped at [`MAX_BRIGHTNESS`].
+    #[must_use]
+    pub fn with_level(mut self, row: usize, col: usize, level: u8) -> Self {
+        if let Some(current) = self.0.get_mut(row).and_then(|levels| levels.get_mut(col)) {
+            *current = level.min(MAX_BRIGHTNESS);
+        }
+        self
+    }
+
+    /// Elements lit during the 0-indexed time slice `slice`, i.e. those brighter than `slice`.
+    pub fn sub_frame(&self, slice: u8) -> Grid {
+        (0..Grid::NUM_ROWS)
+            .flat_map(|row| (0..Grid::NUM_COLS).map(move |col| (row, col)))
+            .filter(|(row, col)| self.0[*row][*col] > slice)
+            .collect()
+    }
+}
+
+impl From<&Grid> for BrightnessGrid {
+    /// Show `grid`'s elements at full brightness.
+    fn from(grid: &Grid) -> Self {
+        grid.cells()
+            .fold(Self::default(), |brightness, (row, col)| {
+                brightness.with_level(row, col, MAX_BRIGHTNESS)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// LED `(x, y)` coordinates of each matrix position, as printed in the v1 schematics
+    /// (`x` counted from the left, `y` from the top, `None` for unconnected positions)
+    const V1_PINS: [[Option<(usize, usize)>; 9]; 3] = [
+        [
+            Some((0, 0)),
+            Some((2, 0)),
+            Some((4, 0)),
+            Some((4, 3)),
+            Some((3, 3)),
+            Some((2, 3)),
+            Some((1, 3)),
+            Some((0, 3)),
+            Some((1, 2)),
+        ],
+        [
+            Some((4, 2)),
+            Some((0, 2)),
+            Some((2, 2)),
+            Some((1, 0)),
+            Some((3, 0)),
+            Some((3, 4)),
+            Some((1, 4)),
+            None,
+            None,
+        ],
+        [
+            Some((2, 4)),
+            Some((4, 4)),
+            Some((0, 4)),
+            Some((0, 1)),
+            Some((1, 1)),
+            Some((2, 1)),
+            Some((3, 1)),
+            Some((4, 1)),
+            Some((3, 2)),
+        ],
+    ];
+
+    fn single(row: usize, col: usize) -> Grid {
+        Grid::default().set_element(row, col).unwrap()
+    }
+
+    #[test]
+    fn v1_pin_table() {
+        for (matrix_row, pins) in V1_PINS.iter().enumerate() {
+            for (matrix_col, led) in pins.iter().enumerate() {
+                let Some((x, y)) = led else { continue };
+                let frame = Revision::V1.scan(&single(Grid::NUM_ROWS - 1 - y, *x));
+
+                for pattern in frame.rows() {
+                    let expected = if pattern.row() == matrix_row {
+                        1 << matrix_col
+                    } else {
+                        0
+                    };
+                    assert_eq!(pattern.cols(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn v2_pin_table() {
+        // bottom left element is the LED in the last row, first column
+        let frame = Revision::V2.scan(&single(0, 0));
+        assert!(frame
+            .rows()
+            .map(|pattern| pattern.cols())
+            .eq([0, 0, 0, 0, 0b1]));
+
+        let frame = Revision::V2.scan(&Grid::R4);
+        let first = frame.rows().next().unwrap();
+        assert_eq!(first.row_pins(), 0b1);
+        assert_eq!(first.col_pins(), 0);
+    }
+
+    #[test]
+    fn full_grid() {
+        let full = !Grid::default();
+
+        assert!(Revision::V1
+            .scan(&full)
+            .rows()
+            .map(|pattern| pattern.cols())
+            .eq([0x1ff, 0x7f, 0x1ff]));
+        assert!(Revision::V2
+            .scan(&full)
+            .rows()
+            .map(|pattern| pattern.cols())
+            .eq([0x1f; 5]));
+
+        // unconnected columns are left high, i.e. off
+        let empty = Revision::V1.scan(&Grid::default());
+        assert!(empty.rows().all(|pattern| pattern.col_pins() == 0x1ff));
+        assert!(empty
+            .rows()
+            .map(|pattern| pattern.row_pins())
+            .eq([0b1, 0b10, 0b100]));
+    }
+
+    #[test]
+    fn pwm_sub_frames() {
+        let grid = BrightnessGrid::default()
+            .with_level(0, 0, 3)
+            .with_level(4, 4, MAX_BRIGHTNESS)
+            .with_level(2, 2, 200);
+
+        let frames: heapless::Vec<ScanFrame, 16> = Revision::V2.sub_frames(&grid).collect();
+        assert_eq!(frames.len(), usize::from(MAX_BRIGHTNESS));
+
+        let lit = |frame: &ScanFrame, row: usize, col: usize| {
+            let (matrix_row, matrix_col) = Revision::V2.matrix_position(row, col).unwrap();
+            frame.rows().nth(matrix_row).unwrap().cols() & (1 << matrix_col) != 0
+        };
+        assert_eq!(frames.iter().filter(|f| lit(f, 0, 0)).count(), 3);
+        assert!(frames.iter().all(|f| lit(f, 4, 4)));
+        assert!(frames.iter().all(|f| lit(f, 2, 2)));
+        assert!(!frames.iter().any(|f| lit(f, 1, 1)));
+    }
+
+    #[test]
+    fn brightness_from_grid() {
+        let grid = Grid::R0 | Grid::R3;
+        let brightness = BrightnessGrid::from(&grid);
+
+        assert_eq!(brightness.level(0, 2), Some(MAX_BRIGHTNESS));
+        assert_eq!(brightness.level(1, 2), Some(0));
+        assert_eq!(brightness.level(5, 0), None);
+        assert_eq!(brightness.sub_frame(0), grid);
+        assert_eq!(brightness.sub_frame(MAX_BRIGHTNESS), Grid::default());
+    }
+}
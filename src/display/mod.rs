@@ -0,0 +1,4 @@
+pub mod diff;
+pub mod microbit;
+pub mod sink;
+pub mod text;
@@ -0,0 +1,250 @@
+use core::{
+    cell::RefCell,
+    sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering},
+};
+
+use crate::{gameplay::game::Observer, geometry::grid::Grid};
+
+/// Active and passive layer of a single frame, see [`Observer::signal_board_changed`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Frame {
+    pub active: Grid,
+    pub passive: Grid,
+}
+
+impl Frame {
+    #[must_use]
+    pub fn new(active: Grid, passive: Grid) -> Self {
+        Self { active, passive }
+    }
+
+    /// Both layers merged into a single grid, for displays not distinguishing the two.
+    pub fn combined(&self) -> Grid {
+        &self.active | &self.passive
+    }
+}
+
+/// Destination of the frames produced by a game, e.g. the LED matrix' driver.
+///
+/// Frames are passed by shared reference as [`Observer`]s are, so sinks have to rely on interior
+/// mutability.
+pub trait DisplaySink {
+    fn show(&self, frame: Frame);
+}
+
+impl<D> DisplaySink for &D
+where
+    D: DisplaySink + ?Sized,
+{
+    fn show(&self, frame: Frame) {
+        (**self).show(frame);
+    }
+}
+
+/// [`Observer`] forwarding every frame to a [`DisplaySink`].
+#[derive(Debug, Default)]
+pub struct SinkObserver<D>(D);
+
+impl<D> SinkObserver<D>
+where
+    D: DisplaySink,
+{
+    pub fn new(sink: D) -> Self {
+        Self(sink)
+    }
+
+    pub fn sink(&self) -> &D {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> D {
+        self.0
+    }
+}
+
+impl<D> Observer for SinkObserver<D>
+where
+    D: DisplaySink,
+{
+    fn signal_board_changed(&self, active: Grid, passive: Grid) {
+        self.0.show(Frame::new(active, passive));
+    }
+}
+
+/// Double-buffered frame shared between the game loop and the display's refresh interrupt.
+///
+/// The game loop [`write`](Self::write)s into the back buffer, the interrupt handler calls
+/// [`swap`](Self::swap) at the start of each refresh cycle and then reads the
+/// [`front`](Self::front) buffer.
+/// A frame is only ever swapped in once it has been written entirely, so the interrupt never sees
+/// the active layer of one frame combined with the passive layer of another.
+///
+/// Only atomic loads and stores are used, so the buffer works on targets without compare and
+/// swap, such as the micro:bit v1.
+/// This relies on [`swap`](Self::swap) never being preempted by [`write`](Self::write), as is
+/// the case when swapping from an interrupt handler and writing from the main loop.
+#[derive(Debug)]
+pub struct FrameBuffer {
+    /// raw active and passive layer of both buffers
+    frames: [[AtomicU32; 2]; 2],
+    front: AtomicUsize,
+    pending: AtomicBool,
+}
+
+impl FrameBuffer {
+    /// Empty buffer, usable to initialize a `static`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            frames: [
+                [AtomicU32::new(0), AtomicU32::new(0)],
+                [AtomicU32::new(0), AtomicU32::new(0)],
+            ],
+            front: AtomicUsize::new(0),
+            pending: AtomicBool::new(false),
+        }
+    }
+
+    /// Write `frame` to the back buffer and mark it for being swapped in.
+    ///
+    /// A frame written before, but not swapped in yet, is discarded.
+    pub fn write(&self, frame: &Frame) {
+        // withdraw the back buffer from the interrupt while writing to it
+        self.pending.store(false, Ordering::Release);
+
+        let back = &self.frames[1 - self.front.load(Ordering::Acquire)];
+        back[0].store(frame.active.to_bits(), Ordering::Relaxed);
+        back[1].store(frame.passive.to_bits(), Ordering::Relaxed);
+
+        self.pending.store(true, Ordering::Release);
+    }
+
+    /// Swap in the frame written last, if any, returning whether a swap took place.
+    ///
+    /// Meant to be called from interrupt context, see the type's documentation.
+    pub fn swap(&self) -> bool {
+        if !self.pending.load(Ordering::Acquire) {
+            return false;
+        }
+        self.pending.store(false, Ordering::Relaxed);
+        self.front
+            .store(1 - self.front.load(Ordering::Relaxed), Ordering::Release);
+        true
+    }
+
+    /// Frame currently swapped in
+    #[must_use]
+    pub fn front(&self) -> Frame {
+        let front = &self.frames[self.front.load(Ordering::Acquire)];
+        let layer = |idx: usize| {
+            Grid::from_bits(front[idx].load(Ordering::Relaxed))
+                .expect("Only valid grids are written to the buffer")
+        };
+        Frame::new(layer(0), layer(1))
+    }
+}
+
+impl Default for FrameBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplaySink for FrameBuffer {
+    fn show(&self, frame: Frame) {
+        self.write(&frame);
+    }
+}
+
+/// In-memory sink recording up to `N` frames, for host-side tests.
+#[derive(Debug, Default)]
+pub struct RecordingSink<const N: usize> {
+    frames: RefCell<heapless::Vec<Frame, N>>,
+    dropped: RefCell<usize>,
+}
+
+impl<const N: usize> RecordingSink<N> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            frames: RefCell::new(heapless::Vec::new()),
+            dropped: RefCell::new(0),
+        }
+    }
+
+    /// Frames recorded so far, oldest first
+    #[must_use]
+    pub fn frames(&self) -> heapless::Vec<Frame, N> {
+        self.frames.borrow().clone()
+    }
+
+    /// Number of frames not recorded due to the sink being full
+    #[must_use]
+    pub fn dropped(&self) -> usize {
+        *self.dropped.borrow()
+    }
+
+    /// Frame recorded last
+    #[must_use]
+    pub fn last(&self) -> Option<Frame> {
+        self.frames.borrow().last().cloned()
+    }
+}
+
+impl<const N: usize> DisplaySink for RecordingSink<N> {
+    fn show(&self, frame: Frame) {
+        if self.frames.borrow_mut().push(frame).is_err() {
+            *self.dropped.borrow_mut() += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double_buffering() {
+        let buffer = FrameBuffer::new();
+        let first = Frame::new(Grid::R4, Grid::R0);
+        let second = Frame::new(Grid::R3, Grid::R0 | Grid::R1);
+
+        assert!(!buffer.swap());
+        assert_eq!(buffer.front(), Frame::default());
+
+        buffer.write(&first);
+        // not swapped in yet
+        assert_eq!(buffer.front(), Frame::default());
+        assert!(buffer.swap());
+        assert_eq!(buffer.front(), first);
+        assert!(!buffer.swap());
+
+        // only the latest of several frames is swapped in
+        buffer.write(&Frame::default());
+        buffer.write(&second);
+        assert_eq!(buffer.front(), first);
+        assert!(buffer.swap());
+        assert_eq!(buffer.front(), second);
+        assert_eq!(buffer.front().combined(), Grid::R0 | Grid::R1 | Grid::R3);
+    }
+
+    #[test]
+    fn recording() {
+        let sink = RecordingSink::<2>::new();
+        let observer = SinkObserver::new(&sink);
+
+        observer.signal_board_changed(Grid::R4, Grid::R0);
+        observer.signal_board_changed(Grid::R3, Grid::R0);
+        observer.signal_board_changed(Grid::R2, Grid::R0);
+
+        assert_eq!(
+            sink.frames().as_slice(),
+            &[
+                Frame::new(Grid::R4, Grid::R0),
+                Frame::new(Grid::R3, Grid::R0)
+            ]
+        );
+        assert_eq!(sink.dropped(), 1);
+        assert_eq!(sink.last(), Some(Frame::new(Grid::R3, Grid::R0)));
+    }
+}
@@ -0,0 +1,286 @@
+use core::str::Chars;
+
+use crate::geometry::grid::Grid;
+
+/// Number of columns of a single glyph.
+pub const GLYPH_COLS: usize = Grid::NUM_COLS;
+
+/// Number of blank columns between two consecutive glyphs of a [`Marquee`].
+pub const SPACING: usize = 1;
+
+/// Rows of a glyph, top row first, leftmost column in the most significant of the five bits.
+type Bitmap = [u8; Grid::NUM_ROWS];
+
+const DIGITS: [Bitmap; 10] = [
+    [0b01110, 0b10011, 0b10101, 0b11001, 0b01110],
+    [0b00100, 0b01100, 0b00100, 0b00100, 0b01110],
+    [0b01110, 0b10001, 0b00110, 0b01000, 0b11111],
+    [0b11110, 0b00001, 0b00110, 0b00001, 0b11110],
+    [0b00110, 0b01010, 0b10010, 0b11111, 0b00010],
+    [0b11111, 0b10000, 0b11110, 0b00001, 0b11110],
+    [0b00111, 0b01000, 0b11110, 0b10001, 0b01110],
+    [0b11111, 0b00010, 0b00100, 0b01000, 0b10000],
+    [0b01110, 0b10001, 0b01110, 0b10001, 0b01110],
+    [0b01110, 0b10001, 0b01111, 0b00010, 0b11100],
+];
+
+const LETTERS: [Bitmap; 26] = [
+    [0b01110, 0b10001, 0b11111, 0b10001, 0b10001],
+    [0b11110, 0b10001, 0b11110, 0b10001, 0b11110],
+    [0b01111, 0b10000, 0b10000, 0b10000, 0b01111],
+    [0b11110, 0b10001, 0b10001, 0b10001, 0b11110],
+    [0b11111, 0b10000, 0b11110, 0b10000, 0b11111],
+    [0b11111, 0b10000, 0b11110, 0b10000, 0b10000],
+    [0b01111, 0b10000, 0b10011, 0b10001, 0b01110],
+    [0b10001, 0b10001, 0b11111, 0b10001, 0b10001],
+    [0b11111, 0b00100, 0b00100, 0b00100, 0b11111],
+    [0b11111, 0b00010, 0b00010, 0b10010, 0b01100],
+    [0b10010, 0b10100, 0b11000, 0b10100, 0b10010],
+    [0b10000, 0b10000, 0b10000, 0b10000, 0b11111],
+    [0b10001, 0b11011, 0b10101, 0b10001, 0b10001],
+    [0b10001, 0b11001, 0b10101, 0b10011, 0b10001],
+    [0b01110, 0b10001, 0b10001, 0b10001, 0b01110],
+    [0b11110, 0b10001, 0b11110, 0b10000, 0b10000],
+    [0b01110, 0b10001, 0b10101, 0b10010, 0b01101],
+    [0b11110, 0b10001, 0b11110, 0b10010, 0b10001],
+    [0b01111, 0b10000, 0b01110, 0b00001, 0b11110],
+    [0b11111, 0b00100, 0b00100, 0b00100, 0b00100],
+    [0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+    [0b10001, 0b10001, 0b10001, 0b01010, 0b00100],
+    [0b10001, 0b10001, 0b10101, 0b11011, 0b10001],
+    [0b10001, 0b01010, 0b00100, 0b01010, 0b10001],
+    [0b10001, 0b01010, 0b00100, 0b00100, 0b00100],
+    [0b11111, 0b00010, 0b00100, 0b01000, 0b11111],
+];
+
+const BLANK: Bitmap = [0; Grid::NUM_ROWS];
+
+fn bitmap(c: char) -> Option<Bitmap> {
+    match c.to_ascii_uppercase() {
+        ' ' => Some(BLANK),
+        c @ '0'..='9' => Some(DIGITS[c as usize - '0' as usize]),
+        c @ 'A'..='Z' => Some(LETTERS[c as usize - 'A' as usize]),
+        _ => None,
+    }
+}
+
+/// Columns of `c`'s glyph, leftmost column first, bottom row in the least significant bit.
+///
+/// Characters without a glyph are rendered blank.
+fn columns(c: char) -> [u8; GLYPH_COLS] {
+    let rows = bitmap(c).unwrap_or(BLANK);
+
+    array_init::array_init(|col| {
+        (0..Grid::NUM_ROWS)
+            .filter(|row| rows[Grid::NUM_ROWS - 1 - row] & (1 << (GLYPH_COLS - 1 - col)) != 0)
+            .fold(0, |column, row| column | (1 << row))
+    })
+}
+
+/// Glyph of `c`, filling the whole grid.
+///
+/// Digits, the space and letters are supported, where lowercase letters share the glyph of their
+/// uppercase counterpart.
+/// Returns `None` for any other character.
+#[must_use]
+pub fn glyph(c: char) -> Option<Grid> {
+    bitmap(c).map(|_| frame(columns(c)))
+}
+
+fn frame(columns: [u8; Grid::NUM_COLS]) -> Grid {
+    let mut grid = Grid::default();
+
+    for (idx_c, column) in columns.iter().enumerate() {
+        for idx_r in 0..Grid::NUM_ROWS {
+            if column & (1 << idx_r) != 0 {
+                grid = grid
+                    .set_element(idx_r, idx_c)
+                    .expect("Hardcoded range should be valid");
+            }
+        }
+    }
+
+    grid
+}
+
+/// Decimal digits of an unsigned integer, most significant digit first.
+#[derive(Clone, Debug)]
+pub struct Digits {
+    value: u32,
+    divisor: u32,
+}
+
+impl Digits {
+    #[must_use]
+    pub fn new(value: u32) -> Self {
+        let mut divisor = 1;
+        while value / divisor >= 10 {
+            divisor *= 10;
+        }
+        Self { value, divisor }
+    }
+}
+
+impl Iterator for Digits {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.divisor == 0 {
+            return None;
+        }
+
+        let digit = (self.value / self.divisor) % 10;
+        self.divisor /= 10;
+        char::from_digit(digit, 10)
+    }
+}
+
+/// Iterator scrolling text across the grid from right to left, one column per frame.
+///
+/// The text enters at the rightmost column, glyphs are separated by [`SPACING`] blank columns and
+/// the text scrolls on until it has left the grid entirely, so the last frame is blank.
+/// Text of `n` characters hence takes `n * (GLYPH_COLS + SPACING) + GLYPH_COLS - 1` frames, no
+/// frames at all for empty text.
+#[derive(Clone, Debug)]
+pub struct Marquee<I> {
+    chars: I,
+    window: [u8; Grid::NUM_COLS],
+    glyph: [u8; GLYPH_COLS],
+    col: usize,
+    trailing: usize,
+}
+
+impl<I> Marquee<I>
+where
+    I: Iterator<Item = char>,
+{
+    pub fn from_chars(chars: I) -> Self {
+        Self {
+            chars,
+            window: [0; Grid::NUM_COLS],
+            glyph: [0; GLYPH_COLS],
+            col: GLYPH_COLS + SPACING,
+            trailing: 0,
+        }
+    }
+
+    fn next_column(&mut self) -> Option<u8> {
+        if self.col == GLYPH_COLS + SPACING {
+            let Some(c) = self.chars.next() else {
+                self.trailing = self.trailing.checked_sub(1)?;
+                return Some(0);
+            };
+            self.glyph = columns(c);
+            self.col = 0;
+            // the spacing after the last glyph already accounts for one blank column
+            self.trailing = Grid::NUM_COLS - SPACING;
+        }
+
+        let column = self.glyph.get(self.col).copied().unwrap_or(0);
+        self.col += 1;
+        Some(column)
+    }
+}
+
+impl<'a> Marquee<Chars<'a>> {
+    /// Scroll `text`, see [`glyph`] for the supported characters.
+    #[must_use]
+    pub fn new(text: &'a str) -> Self {
+        Self::from_chars(text.chars())
+    }
+}
+
+impl Marquee<Digits> {
+    /// Scroll the decimal representation of `value`.
+    #[must_use]
+    pub fn number(value: u32) -> Self {
+        Self::from_chars(Digits::new(value))
+    }
+}
+
+impl<I> Iterator for Marquee<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = Grid;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let column = self.next_column()?;
+
+        self.window.rotate_left(1);
+        self.window[Grid::NUM_COLS - 1] = column;
+
+        Some(frame(self.window))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid;
+
+    #[test]
+    fn glyphs() {
+        assert_eq!(
+            glyph('1'),
+            Some(grid!(
+                "
+                +-----+
+                |  x  |
+                | xx  |
+                |  x  |
+                |  x  |
+                | xxx |
+                +-----+
+                "
+            ))
+        );
+        assert_eq!(glyph('q'), glyph('Q'));
+        assert_eq!(glyph(' '), Some(Grid::default()));
+        assert_eq!(glyph('?'), None);
+    }
+
+    #[test]
+    fn digits() {
+        assert!(Digits::new(0).eq(['0']));
+        assert!(Digits::new(1907).eq(['1', '9', '0', '7']));
+        assert!(Digits::new(u32::MAX).eq("4294967295".chars()));
+    }
+
+    #[test]
+    fn marquee_scrolls_through() {
+        let frames: heapless::Vec<Grid, 32> = Marquee::new("L").collect();
+
+        assert_eq!(frames.len(), GLYPH_COLS + SPACING + GLYPH_COLS - 1);
+        assert_eq!(
+            frames[0],
+            grid!(
+                "
+                +-----+
+                |    x|
+                |    x|
+                |    x|
+                |    x|
+                |    x|
+                +-----+
+                "
+            )
+        );
+        assert_eq!(Some(&frames[GLYPH_COLS - 1]), glyph('L').as_ref());
+        assert_eq!(frames.last(), Some(&Grid::default()));
+    }
+
+    #[test]
+    fn marquee_separates_glyphs() {
+        let mut frames = Marquee::number(42).skip(GLYPH_COLS - 1);
+
+        assert_eq!(frames.next(), glyph('4'));
+        assert_eq!(frames.nth(GLYPH_COLS + SPACING - 1), glyph('2'));
+        assert_eq!(frames.count(), Grid::NUM_COLS);
+    }
+
+    #[test]
+    fn empty_marquee() {
+        assert_eq!(Marquee::new("").count(), 0);
+    }
+}
@@ -20,21 +20,32 @@ mod sealed {
 
 pub trait State: sealed::Seal {}
 
-#[derive(Debug)]
-pub struct TakesTile;
+#[derive(Clone, Debug, Default)]
+pub struct TakesTile {
+    /// Rows cleared after freezing the most recent tile
+    last_clear: ClearReport,
+}
 
 impl sealed::Seal for TakesTile {}
 impl State for TakesTile {}
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ProcessesRows {
     /// 0-indexed, but with respect to a `Grid`'s (as opposed to `ExtGrid`'s) row count.
     current: usize,
+    /// Animation played on the current row alongside the number of steps played so far
+    animation: Option<(RowAnimation, usize)>,
+    /// Rows cleared so far
+    report: ClearReport,
 }
 
 impl ProcessesRows {
-    fn new(current: usize) -> Self {
-        Self { current }
+    fn new(current: usize, report: ClearReport) -> Self {
+        Self {
+            current,
+            animation: None,
+            report,
+        }
     }
 }
 
@@ -43,30 +54,319 @@ impl State for ProcessesRows {}
 
 impl Default for ProcessesRows {
     fn default() -> Self {
-        Self::new(0)
+        Self::new(0, ClearReport::default())
+    }
+}
+
+/// Visual effect played on a fully populated row before discarding it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RowAnimationKind {
+    /// Alternately hide and show the row
+    Blink,
+    /// Clear the row's cells from left to right
+    Sweep,
+}
+
+/// Animation of a fully populated row, see [`Board::process_row_animated`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RowAnimation {
+    pub kind: RowAnimationKind,
+    /// Number of animation steps before the row is discarded
+    pub steps: usize,
+}
+
+impl RowAnimation {
+    /// Cells of `row` to show at animation step `step` (counted from 1).
+    fn frame(self, row: &Grid, step: usize) -> Grid {
+        match self.kind {
+            RowAnimationKind::Blink => {
+                if step.is_multiple_of(2) {
+                    row.clone()
+                } else {
+                    Grid::default()
+                }
+            }
+            RowAnimationKind::Sweep => {
+                // number of cleared columns, rounded up so that the last step clears all of them
+                let cleared = (step * BOARD_COLS).div_ceil(self.steps.max(1));
+                let mut frame = row.clone();
+                for col in 0..cleared.min(BOARD_COLS) {
+                    for r in 0..BOARD_ROWS {
+                        frame = frame
+                            .clear_element(r, col)
+                            .expect("Hardcoded range should be valid");
+                    }
+                }
+                frame
+            }
+        }
+    }
+}
+
+/// Rows cleared after freezing a tile, see [`Board::process_all_rows`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClearReport {
+    rows: heapless::Vec<usize, BOARD_ROWS>,
+    chain: usize,
+    perfect_clear: bool,
+}
+
+impl ClearReport {
+    /// Record the rows given by `mask`, see [`Grid::full_row_mask`], bottom row first.
+    fn record_mask(&mut self, mask: u8) {
+        for row in (0..BOARD_ROWS).filter(|row| mask & (1 << row) != 0) {
+            self.record(row);
+        }
+    }
+
+    fn record(&mut self, row: usize) {
+        // Each cleared row takes five cells, but there are at most four rows' worth of cells on
+        // the board (no row being fully populated) plus the frozen tile's cells
+        self.rows
+            .push(row)
+            .expect("At most four rows can be cleared at once");
+    }
+
+    /// Indices of the cleared rows in the order of clearing.
+    ///
+    /// Indices refer to the rows' positions when the tile has been frozen, i.e. before any row
+    /// has been discarded. Rows cleared by a chain reaction (see [`ClearReport::chain`]) are
+    /// reported at their position after cells have fallen into gaps.
+    #[must_use]
+    pub fn rows(&self) -> &[usize] {
+        &self.rows
+    }
+
+    /// Number of cleared rows
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Number of clear rounds, see [`Board::chain`].
+    #[must_use]
+    pub fn chain(&self) -> usize {
+        self.chain
+    }
+
+    /// Whether clearing rows left the board empty
+    #[must_use]
+    pub fn is_perfect_clear(&self) -> bool {
+        self.perfect_clear
+    }
+}
+
+/// Rule deciding how cells above a discarded row fall.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Gravity {
+    /// Rows above a discarded row move down by one as a whole, possibly leaving cells floating
+    /// above gaps
+    #[default]
+    Naive,
+    /// Connected blocks of cells fall as a unit until they rest on the floor or on other cells
+    Sticky,
+    /// Individual cells fall until they rest on the floor or on other cells
+    Cascade,
+}
+
+impl Gravity {
+    /// Discard all fully populated rows of `grid` and let the remaining cells fall, returning
+    /// the resulting grid alongside the discarded rows as a mask.
+    fn clear_round(self, grid: Grid) -> (Grid, u8) {
+        let mask = grid.full_row_mask();
+        let grid = match self {
+            Gravity::Naive => grid.discard_rows(mask),
+            Gravity::Sticky | Gravity::Cascade => {
+                Grid::from_row_mask(mask).map(|full| self.settle(grid.subtract(&full)))
+            }
+        }
+        .expect("Mask has been obtained from the grid");
+        (grid, mask)
+    }
+
+    /// Let the cells of `grid` fall according to the rule.
+    ///
+    /// [`Gravity::Naive`] leaves `grid` as is, since it is applied while discarding rows.
+    fn settle(self, grid: Grid) -> Grid {
+        match self {
+            Gravity::Naive => grid,
+            Gravity::Sticky => settle_blocks(grid),
+            Gravity::Cascade => settle_cells(&grid),
+        }
+    }
+}
+
+/// Compact each column of `grid` towards the bottom row.
+fn settle_cells(grid: &Grid) -> Grid {
+    let mut settled = Grid::default();
+
+    for col in 0..BOARD_COLS {
+        let height = (0..BOARD_ROWS)
+            .filter(|row| {
+                grid.is_element_set(*row, col)
+                    .expect("Hardcoded range should be valid")
+            })
+            .count();
+        for row in 0..height {
+            settled = settled
+                .set_element(row, col)
+                .expect("Hardcoded range should be valid");
+        }
+    }
+
+    settled
+}
+
+/// Lower connected blocks of cells of `grid` one row at a time until none of them can move.
+fn settle_blocks(mut grid: Grid) -> Grid {
+    'settling: loop {
+        let mut visited = Grid::default();
+
+        for row in 0..BOARD_ROWS {
+            for col in 0..BOARD_COLS {
+                let cell = Grid::default()
+                    .set_element(row, col)
+                    .expect("Hardcoded range should be valid");
+                if !grid.contains(&cell) || visited.contains(&cell) {
+                    continue;
+                }
+
+                let block = connected_block(&grid, row, col);
+                visited = visited.union(&block);
+
+                if block.overlaps(&Grid::R0) {
+                    continue;
+                }
+                let rest = grid.subtract(&block);
+                let lowered = block
+                    .discard_and_shift(0)
+                    .expect("Hardcoded range should be valid");
+                if !rest.overlaps(&lowered) {
+                    // blocks may have merged, so start over
+                    grid = rest.union(&lowered);
+                    continue 'settling;
+                }
+            }
+        }
+
+        return grid;
     }
 }
 
+/// Block of horizontally or vertically connected cells of `grid` containing `(row, col)`.
+fn connected_block(grid: &Grid, row: usize, col: usize) -> Grid {
+    let mut block = Grid::default();
+    let mut pending = heapless::Vec::<(usize, usize), { BOARD_ROWS * BOARD_COLS }>::new();
+
+    block = block
+        .set_element(row, col)
+        .expect("Hardcoded range should be valid");
+    pending
+        .push((row, col))
+        .expect("Each cell is visited at most once");
+
+    while let Some((row, col)) = pending.pop() {
+        let neighbours = [
+            (row.checked_sub(1), Some(col)),
+            (Some(row + 1), Some(col)),
+            (Some(row), col.checked_sub(1)),
+            (Some(row), Some(col + 1)),
+        ];
+
+        for (r, c) in neighbours {
+            let (Some(r), Some(c)) = (r, c) else {
+                continue;
+            };
+            if grid.is_element_set(r, c).unwrap_or(false)
+                && !block.is_element_set(r, c).unwrap_or(true)
+            {
+                block = block
+                    .set_element(r, c)
+                    .expect("Index has been checked before");
+                pending
+                    .push((r, c))
+                    .expect("Each cell is visited at most once");
+            }
+        }
+    }
+
+    block
+}
+
 #[derive(Debug)]
 pub enum BoardError {
     InvalidPosition,
+    /// Fully populated row `row`, which should have been discarded already
+    FullRow(usize),
+    /// Empty row `row` below a non-empty row, which is not reachable by dropping tiles
+    EmptyRow(usize),
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Board<S> {
     state: S,
     grid: ExtGrid,
+    gravity: Gravity,
+}
+
+impl<S> Board<S> {
+    #[must_use]
+    pub fn gravity(&self) -> Gravity {
+        self.gravity
+    }
 }
 
 impl Board<TakesTile> {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            state: TakesTile {},
+            state: TakesTile::default(),
             grid: ExtGrid::RIM,
+            gravity: Gravity::Naive,
         }
     }
 
+    /// Rows cleared after freezing the most recent tile, empty if no tile has been frozen yet.
+    #[must_use]
+    pub fn last_clear(&self) -> &ClearReport {
+        &self.state.last_clear
+    }
+
+    /// Let cells fall according to `gravity` after discarding rows.
+    #[must_use]
+    pub fn with_gravity(self, gravity: Gravity) -> Self {
+        Self { gravity, ..self }
+    }
+
+    /// Create a board holding the cells set in `grid`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoardError::FullRow`] iff `grid` contains a fully populated row and
+    /// [`BoardError::EmptyRow`] iff `grid` contains an empty row below a non-empty row.
+    /// In both cases, the lowest offending row is reported.
+    pub fn from_grid(grid: Grid) -> Result<Self, BoardError> {
+        let fill_counts = grid.row_fill_counts();
+
+        if let Some(row) = fill_counts.iter().position(|count| *count == BOARD_COLS) {
+            return Err(BoardError::FullRow(row));
+        }
+
+        let height = fill_counts
+            .iter()
+            .rposition(|count| *count != 0)
+            .map_or(0, |row| row + 1);
+        if let Some(row) = fill_counts[..height].iter().position(|count| *count == 0) {
+            return Err(BoardError::EmptyRow(row));
+        }
+
+        Ok(Self {
+            state: TakesTile::default(),
+            grid: ExtGrid::from(grid).union(&ExtGrid::RIM),
+            gravity: Gravity::Naive,
+        })
+    }
+
     #[must_use]
     pub fn is_position_valid<T>(&self, tile: &T) -> bool
     where
@@ -108,10 +408,33 @@ impl Board<TakesTile> {
         Board {
             state: ProcessesRows::default(),
             grid: self.grid.union(&raster),
+            gravity: self.gravity,
         }
     }
 }
 
+macro_rules! delegate_metric {
+    ($metric:ident, $ret:ty) => {
+        #[doc = concat!("See [`Grid::", stringify!($metric), "`]")]
+        #[must_use]
+        pub fn $metric(&self) -> $ret {
+            self.grid.clone().center().$metric()
+        }
+    };
+}
+
+impl Board<TakesTile> {
+    delegate_metric!(row_fill_counts, [usize; BOARD_ROWS]);
+    delegate_metric!(full_rows, usize);
+    delegate_metric!(column_heights, [usize; BOARD_COLS]);
+    delegate_metric!(max_height, usize);
+    delegate_metric!(aggregate_height, usize);
+    delegate_metric!(column_holes, [usize; BOARD_COLS]);
+    delegate_metric!(holes, usize);
+    delegate_metric!(bumpiness, usize);
+    delegate_metric!(wells, [usize; BOARD_COLS]);
+}
+
 impl Default for Board<TakesTile> {
     #[must_use]
     fn default() -> Self {
@@ -126,7 +449,43 @@ impl Rasterization<Passive> for Board<TakesTile> {
 }
 
 impl Board<ProcessesRows> {
+    /// Number of clear rounds so far.
+    ///
+    /// The first round discards the rows completed by the frozen tile. Under [`Gravity::Sticky`]
+    /// and [`Gravity::Cascade`], every further round discards rows completed by cells falling
+    /// into gaps, i.e. a chain reaction. Under [`Gravity::Naive`], all rows are discarded within
+    /// a single round.
+    #[must_use]
+    pub fn chain(&self) -> usize {
+        self.state.report.chain()
+    }
+
+    /// Rows cleared so far, see [`Board::process_all_rows`].
+    #[must_use]
+    pub fn report(&self) -> &ClearReport {
+        &self.state.report
+    }
+
+    /// Process all rows, returning the resulting board alongside the rows cleared.
+    ///
+    /// The report is also available afterwards via [`Board::last_clear`].
+    #[must_use]
+    pub fn process_all_rows(mut self) -> (Board<TakesTile>, ClearReport) {
+        loop {
+            self = match self.process_row() {
+                Either::Left(board) => board,
+                Either::Right(board) => {
+                    let report = board.last_clear().clone();
+                    return (board, report);
+                }
+            }
+        }
+    }
+
     /// To leave [`ProcessesRows`] state, call `process_row` once per non-empty row.
+    ///
+    /// Under [`Gravity::Sticky`] and [`Gravity::Cascade`], discarding rows restarts processing
+    /// at the bottom row, since falling cells may complete any row.
     #[must_use]
     pub fn process_row(self) -> Either<Board<ProcessesRows>, Board<TakesTile>> {
         // Note that by design, the bottom row cannot be empty when entering
@@ -142,9 +501,10 @@ impl Board<ProcessesRows> {
 
         let next_row;
         let pruned_grid;
+        let mut report = self.state.report;
 
         // Check next row
-        if fully_populated {
+        if fully_populated && self.gravity == Gravity::Naive {
             // Move all rows by one and clear the topmost row
             let shifted = self
                 .grid
@@ -156,6 +516,17 @@ impl Board<ProcessesRows> {
             // above might be fully populated, too.
             next_row = self.state.current;
             pruned_grid = ExtGrid::from(shifted).union(&ExtGrid::RIM);
+            // rows are discarded bottom up, so all rows discarded before used to be below
+            report.record(self.state.current + report.count());
+            report.chain = 1;
+        } else if fully_populated {
+            // Discard all rows completed at once, so that they count towards the same round
+            let (grid, mask) = self.gravity.clear_round(self.grid.center());
+            report.record_mask(mask);
+            report.chain += 1;
+
+            next_row = 0;
+            pruned_grid = ExtGrid::from(grid).union(&ExtGrid::RIM);
         } else {
             next_row = self.state.current + 1;
             pruned_grid = self.grid;
@@ -163,46 +534,124 @@ impl Board<ProcessesRows> {
 
         // Guard lookahead, so that we do not check beyond the board's extend
         if next_row >= BOARD_ROWS {
-            return Either::Right(Board {
-                state: TakesTile {},
-                grid: pruned_grid,
-            });
+            return Either::Right(Self::finish(pruned_grid, self.gravity, report));
         }
 
         // There are no empty interleaving rows, so once we encounter an empty row, we can skip
         // ahead
         let next_row_empty = !pruned_grid.overlaps(&Grid::ROWS[next_row].clone().into());
         if next_row_empty {
-            Either::Right(Board {
-                state: TakesTile {},
-                grid: pruned_grid,
-            })
+            Either::Right(Self::finish(pruned_grid, self.gravity, report))
         } else {
             Either::Left(Board {
-                state: ProcessesRows::new(next_row),
+                state: ProcessesRows::new(next_row, report),
                 grid: pruned_grid,
+                gravity: self.gravity,
             })
         }
     }
 }
 
+impl Board<ProcessesRows> {
+    /// Process all rows in one go, skipping the intermediate states [`Board::process_row`] steps
+    /// through.
+    ///
+    /// Results in the same board and report as [`Board::process_all_rows`], but takes only a few
+    /// bit operations per clear round, which suits headless simulation (e.g. by an AI).
+    #[must_use]
+    pub fn process_rows_instantly(self) -> (Board<TakesTile>, ClearReport) {
+        let mut grid = self.grid.center();
+        let mut report = self.state.report;
+
+        while grid.full_row_mask() != 0 {
+            let (next, mask) = self.gravity.clear_round(grid);
+            grid = next;
+            report.record_mask(mask);
+            report.chain += 1;
+
+            // Naive gravity never completes further rows
+            if self.gravity == Gravity::Naive {
+                break;
+            }
+        }
+
+        let board = Self::finish(
+            ExtGrid::from(grid).union(&ExtGrid::RIM),
+            self.gravity,
+            report,
+        );
+        let report = board.last_clear().clone();
+        (board, report)
+    }
+
+    fn finish(grid: ExtGrid, gravity: Gravity, mut report: ClearReport) -> Board<TakesTile> {
+        report.perfect_clear = report.count() > 0 && grid == ExtGrid::RIM;
+        Board {
+            state: TakesTile { last_clear: report },
+            grid,
+            gravity,
+        }
+    }
+
+    /// Like [`Board::process_row`], but play `animation` on a fully populated row before
+    /// discarding it.
+    ///
+    /// Each animation step takes one call, i.e. a fully populated row is discarded only after
+    /// `animation.steps` calls. In between, the [`Active`] raster shows the animation's frames.
+    /// Under [`Gravity::Sticky`] and [`Gravity::Cascade`], all fully populated rows are
+    /// discarded at once and hence animated together.
+    #[must_use]
+    pub fn process_row_animated(
+        self,
+        animation: RowAnimation,
+    ) -> Either<Board<ProcessesRows>, Board<TakesTile>> {
+        let fully_populated = self
+            .grid
+            .contains(&Grid::ROWS[self.state.current].clone().into());
+        let played = self.state.animation.map_or(0, |(_, played)| played);
+
+        if fully_populated && played < animation.steps {
+            Either::Left(Board {
+                state: ProcessesRows {
+                    animation: Some((animation, played + 1)),
+                    ..self.state
+                },
+                grid: self.grid,
+                gravity: self.gravity,
+            })
+        } else {
+            self.process_row()
+        }
+    }
+
+    /// Rows the next call to `process_row` discards, or the current row if it is not fully
+    /// populated.
+    fn current_rows(&self) -> Grid {
+        let grid = self.grid.clone().center();
+        let current = Grid::ROWS[self.state.current].clone();
+
+        if self.gravity != Gravity::Naive && grid.contains(&current) {
+            Grid::from_row_mask(grid.full_row_mask()).unwrap_or(current)
+        } else {
+            current
+        }
+    }
+}
+
 impl Rasterization<Passive> for Board<ProcessesRows> {
     fn rasterize_buf(&self, out: &mut Grid) {
-        *out = self
-            .grid
-            .clone()
-            .center()
-            .subtract(&Grid::ROWS[self.state.current].clone());
+        *out = self.grid.clone().center().subtract(&self.current_rows());
     }
 }
 
 impl Rasterization<Active> for Board<ProcessesRows> {
     fn rasterize_buf(&self, out: &mut Grid) {
-        *out = self
-            .grid
-            .clone()
-            .center()
-            .intersect(&Grid::ROWS[self.state.current].clone());
+        let rows = self.grid.clone().center().intersect(&self.current_rows());
+
+        *out = match self.state.animation {
+            Some((animation, played)) => animation.frame(&rows, played),
+            None => rows,
+        };
     }
 }
 
@@ -216,6 +665,168 @@ impl<S> Rasterization<{ BOARD_ROWS + 2 }, { BOARD_COLS + 2 }> for Board<S> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{gameplay::raster::RasterizationExt, grid};
+
+    #[test]
+    fn from_grid() -> Result<(), BoardError> {
+        let grid = Grid::from([
+            [true, true, false, true, true],
+            [false, true, false, false, false],
+            [false; BOARD_COLS],
+            [false; BOARD_COLS],
+            [false; BOARD_COLS],
+        ]);
+
+        let board = Board::from_grid(grid.clone())?;
+
+        assert_eq!(
+            <Board<TakesTile> as RasterizationExt<Passive>>::rasterize(&board),
+            grid
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_grid_rejects_full_row() {
+        let grid = Grid::from([
+            [true, true, false, true, true],
+            [true; BOARD_COLS],
+            [false; BOARD_COLS],
+            [false; BOARD_COLS],
+            [false; BOARD_COLS],
+        ]);
+
+        assert!(matches!(
+            Board::from_grid(grid),
+            Err(BoardError::FullRow(1))
+        ));
+    }
+
+    #[test]
+    fn from_grid_rejects_empty_row() {
+        let grid = Grid::from([
+            [true, true, false, true, true],
+            [false; BOARD_COLS],
+            [false, true, false, false, false],
+            [false; BOARD_COLS],
+            [false; BOARD_COLS],
+        ]);
+
+        assert!(matches!(
+            Board::from_grid(grid),
+            Err(BoardError::EmptyRow(1))
+        ));
+    }
+
+    fn full_bottom_row() -> Board<ProcessesRows> {
+        Board {
+            state: ProcessesRows::default(),
+            grid: ExtGrid::from(Grid::R0.union(&Grid::default().set_element(1, 2).unwrap()))
+                .union(&ExtGrid::RIM),
+            gravity: Gravity::Naive,
+        }
+    }
+
+    fn animate(board: Board<ProcessesRows>, animation: RowAnimation) -> Board<ProcessesRows> {
+        match board.process_row_animated(animation) {
+            Either::Left(board) => board,
+            Either::Right(_) => panic!("Animation ended too early"),
+        }
+    }
+
+    #[test]
+    fn blink() {
+        let animation = RowAnimation {
+            kind: RowAnimationKind::Blink,
+            steps: 3,
+        };
+        let mut board = full_bottom_row();
+
+        for expected in [Grid::default(), Grid::R0, Grid::default()] {
+            board = animate(board, animation);
+            assert_eq!(
+                <Board<ProcessesRows> as RasterizationExt<Active>>::rasterize(&board),
+                expected
+            );
+        }
+
+        // The row is discarded by the next call, the row above moving down
+        let board = animate(board, animation);
+        assert_eq!(
+            <Board<ProcessesRows> as RasterizationExt<Active>>::rasterize(&board),
+            Grid::default().set_element(0, 2).unwrap()
+        );
+        assert!(board.process_row_animated(animation).is_right());
+    }
+
+    #[test]
+    fn sweep() {
+        let animation = RowAnimation {
+            kind: RowAnimationKind::Sweep,
+            steps: 2,
+        };
+        let board = animate(full_bottom_row(), animation);
+
+        assert_eq!(
+            <Board<ProcessesRows> as RasterizationExt<Active>>::rasterize(&board),
+            Grid::from([
+                [false, false, false, true, true],
+                [false; BOARD_COLS],
+                [false; BOARD_COLS],
+                [false; BOARD_COLS],
+                [false; BOARD_COLS],
+            ])
+        );
+
+        let board = animate(board, animation);
+        assert_eq!(
+            <Board<ProcessesRows> as RasterizationExt<Active>>::rasterize(&board),
+            Grid::default()
+        );
+    }
+
+    #[test]
+    fn animate_chain_round() {
+        let animation = RowAnimation {
+            kind: RowAnimationKind::Blink,
+            steps: 2,
+        };
+        let mut board = Board {
+            state: ProcessesRows::default(),
+            grid: ExtGrid::from(grid!(
+                "
+                +-----+
+                |     |
+                |     |
+                |  x  |
+                |xxxxx|
+                |xxxxx|
+                +-----+
+                "
+            ))
+            .union(&ExtGrid::RIM),
+            gravity: Gravity::Cascade,
+        };
+
+        // Both rows are discarded within the same round, so they are animated together
+        for _ in 0..2 {
+            board = animate(board, animation);
+        }
+        assert_eq!(
+            <Board<ProcessesRows> as RasterizationExt<Active>>::rasterize(&board),
+            Grid::R0.union(&Grid::R1)
+        );
+        assert_eq!(
+            <Board<ProcessesRows> as RasterizationExt<Passive>>::rasterize(&board),
+            Grid::default().set_element(2, 2).unwrap()
+        );
+
+        let board = animate(board, animation);
+        assert_eq!(
+            <Board<ProcessesRows> as RasterizationExt<Active>>::rasterize(&board),
+            Grid::default().set_element(0, 2).unwrap()
+        );
+    }
 
     #[test]
     fn process_rows() {
@@ -242,6 +853,7 @@ mod tests {
         let mut board = Board::<ProcessesRows> {
             state: ProcessesRows::default(),
             grid: initial_grid.into(),
+            gravity: Gravity::Naive,
         };
 
         // Four rows are non-empty of which two rows are fully populated, hence we have to call
@@ -261,4 +873,209 @@ mod tests {
 
         assert_eq!(board.grid, final_grid.into())
     }
+
+    /// Process all rows of `grid` under `gravity`, returning the final grid and chain count.
+    fn settle(grid: Grid, gravity: Gravity) -> (Grid, usize) {
+        let mut board = Board {
+            state: ProcessesRows::default(),
+            grid: ExtGrid::from(grid).union(&ExtGrid::RIM),
+            gravity,
+        };
+        let mut chain = 0;
+
+        loop {
+            board = match board.process_row() {
+                Either::Left(board) => {
+                    chain = chain.max(board.chain());
+                    board
+                }
+                Either::Right(board) => {
+                    return (
+                        <Board<TakesTile> as RasterizationExt<Passive>>::rasterize(&board),
+                        chain,
+                    )
+                }
+            }
+        }
+    }
+
+    const OVERHANG: Grid = grid!(
+        "
+        +-----+
+        |     |
+        |     |
+        |  xxx|
+        |xxx  |
+        |xxxxx|
+        +-----+
+        "
+    );
+
+    #[test]
+    fn naive_gravity() {
+        let (grid, chain) = settle(OVERHANG, Gravity::Naive);
+
+        assert_eq!(
+            grid,
+            grid!(
+                "
+                +-----+
+                |     |
+                |     |
+                |     |
+                |  xxx|
+                |xxx  |
+                +-----+
+                "
+            )
+        );
+        assert_eq!(chain, 1);
+    }
+
+    #[test]
+    fn sticky_gravity() {
+        // The remaining cells form a single block, which rests on the floor already
+        let (grid, chain) = settle(OVERHANG, Gravity::Sticky);
+
+        assert_eq!(grid, settle(OVERHANG, Gravity::Naive).0);
+        assert_eq!(chain, 1);
+
+        // Separate blocks fall on their own, completing the bottom row once more
+        let (grid, chain) = settle(
+            grid!(
+                "
+                +-----+
+                |     |
+                |    x|
+                |  xxx|
+                |xx   |
+                |xxxxx|
+                +-----+
+                "
+            ),
+            Gravity::Sticky,
+        );
+        assert_eq!(
+            grid,
+            grid!(
+                "
+                +-----+
+                |     |
+                |     |
+                |     |
+                |     |
+                |    x|
+                +-----+
+                "
+            )
+        );
+        assert_eq!(chain, 2);
+    }
+
+    #[test]
+    fn cascade_gravity() {
+        let (grid, chain) = settle(OVERHANG, Gravity::Cascade);
+
+        assert_eq!(grid, Grid::default().set_element(0, 2).unwrap());
+        assert_eq!(chain, 2);
+    }
+
+    #[test]
+    fn clear_report() {
+        let grid = grid!(
+            "
+            +-----+
+            |     |
+            |xxxxx|
+            | x x |
+            |xxxxx|
+            |x x x|
+            +-----+
+            "
+        );
+        let board = Board::from_grid(Grid::default()).unwrap();
+        assert_eq!(board.last_clear(), &ClearReport::default());
+
+        // Rows are reported at their original position
+        let (board, report) = Board {
+            state: ProcessesRows::default(),
+            grid: ExtGrid::from(grid).union(&ExtGrid::RIM),
+            gravity: Gravity::Naive,
+        }
+        .process_all_rows();
+        assert_eq!(report.rows(), &[1, 3]);
+        assert_eq!(report.count(), 2);
+        assert_eq!(report.chain(), 1);
+        assert!(!report.is_perfect_clear());
+        assert_eq!(board.last_clear(), &report);
+
+        let (_, report) = full_bottom_row().process_all_rows();
+        assert!(!report.is_perfect_clear());
+
+        let (_, report) = Board::new()
+            .freeze_tile_assume_valid(ExtGrid::from(Grid::R0))
+            .process_all_rows();
+        assert_eq!(report.rows(), &[0]);
+        assert!(report.is_perfect_clear());
+    }
+
+    #[test]
+    fn chain_reaction_report() {
+        let (_, report) = Board {
+            state: ProcessesRows::default(),
+            grid: ExtGrid::from(OVERHANG).union(&ExtGrid::RIM),
+            gravity: Gravity::Cascade,
+        }
+        .process_all_rows();
+
+        assert_eq!(report.rows(), &[0, 0]);
+        assert_eq!(report.chain(), 2);
+        assert!(!report.is_perfect_clear());
+    }
+
+    #[test]
+    fn instant_processing_matches_stepwise() {
+        let grids = [
+            OVERHANG,
+            grid!(
+                "
+                +-----+
+                |     |
+                |xxxxx|
+                | x x |
+                |xxxxx|
+                |x x x|
+                +-----+
+                "
+            ),
+            grid!(
+                "
+                +-----+
+                |     |
+                |    x|
+                |  xxx|
+                |xx   |
+                |xxxxx|
+                +-----+
+                "
+            ),
+            Grid::R0.union(&Grid::R1),
+        ];
+
+        for gravity in [Gravity::Naive, Gravity::Sticky, Gravity::Cascade] {
+            for grid in &grids {
+                let board = Board {
+                    state: ProcessesRows::default(),
+                    grid: ExtGrid::from(grid.clone()).union(&ExtGrid::RIM),
+                    gravity,
+                };
+
+                let (stepwise, expected) = board.clone().process_all_rows();
+                let (instant, report) = board.process_rows_instantly();
+
+                assert_eq!(report, expected);
+                assert_eq!(instant.grid, stepwise.grid);
+            }
+        }
+    }
 }
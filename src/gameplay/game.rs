@@ -1,11 +1,13 @@
 use crate::{
     gameplay::{
-        board::{Board, ProcessesRows as BoardProcesses, TakesTile, BOARD_COLS, BOARD_ROWS},
+        board::{Board, ClearReport, ProcessesRows as BoardProcesses, RowAnimation, TakesTile},
+        placement::{find_path, Move, Path, Placement},
         raster::{Active, Passive, Rasterization, RasterizationExt},
+        spawn::SpawnPolicy,
     },
     geometry::{
         grid::{ExtGrid, Grid},
-        tile::{BasicTile, Dimensionee, DisplacedTile, Displacee, RotatedTile, Rotatee},
+        tile::{Angle, BasicTile, DisplacedTile, Displacee, RotatedTile, Rotatee},
     },
 };
 
@@ -81,6 +83,35 @@ impl Over {
 impl sealed::Seal for Over {}
 impl State for Over {}
 
+/// States a game can be paused in, see [`Game::pause`].
+pub trait Pausable: State {}
+
+impl Pausable for TileNeeded {}
+impl Pausable for TileFloating {}
+impl Pausable for ProcessRows {}
+
+/// Paused game, holding on to the state it has been paused in.
+#[derive(Debug)]
+pub struct Paused<S> {
+    previous: S,
+}
+
+impl<S> sealed::Seal for Paused<S> where S: Pausable {}
+impl<S> State for Paused<S> where S: Pausable {}
+
+/// Active raster reported while a game is [`Paused`].
+pub const PAUSE_ICON: Grid = crate::grid!(
+    "
+    +-----+
+    |     |
+    | x x |
+    | x x |
+    | x x |
+    |     |
+    +-----+
+    "
+);
+
 pub trait Observer {
     fn signal_board_changed(&self, active: Grid, passive: Grid);
 }
@@ -91,20 +122,6 @@ impl Observer for NoopObserver {
     fn signal_board_changed(&self, _: Grid, _: Grid) {}
 }
 
-enum Direction {
-    Left,
-    Right,
-}
-
-impl From<Direction> for i8 {
-    fn from(value: Direction) -> Self {
-        match value {
-            Direction::Left => -1,
-            Direction::Right => 1,
-        }
-    }
-}
-
 #[derive(Debug)]
 pub enum GameError {
     ObserverFull,
@@ -116,6 +133,7 @@ pub enum GameError {
 pub struct Game<S, O> {
     s: S,
     observer: Option<O>,
+    spawn: SpawnPolicy,
 }
 
 impl<O> Game<TileNeeded, O> {
@@ -124,8 +142,31 @@ impl<O> Game<TileNeeded, O> {
         Self {
             s: TileNeeded::default(),
             observer: None,
+            spawn: SpawnPolicy::default(),
+        }
+    }
+
+    /// Start a game on a pre-populated `board`, see [`Board::from_grid`].
+    #[must_use]
+    pub fn with_board(board: Board<TakesTile>) -> Self {
+        Self {
+            s: TileNeeded::new(board),
+            observer: None,
+            spawn: SpawnPolicy::default(),
         }
     }
+
+    /// See [`Board::last_clear`].
+    #[must_use]
+    pub fn last_clear(&self) -> &ClearReport {
+        self.s.board.last_clear()
+    }
+
+    /// Spawn tiles according to `spawn` instead of [`SpawnPolicy::default`].
+    #[must_use]
+    pub fn with_spawn_policy(self, spawn: SpawnPolicy) -> Self {
+        Self { spawn, ..self }
+    }
 }
 
 pub trait SignalSource: sealed::Seal {
@@ -190,8 +231,12 @@ where
     Self: SignalSource,
 {
     #[must_use]
-    fn new_with_signal(s: S, o: Option<O>) -> Self {
-        let game = Self { s, observer: o };
+    fn new_with_signal(s: S, o: Option<O>, spawn: SpawnPolicy) -> Self {
+        let game = Self {
+            s,
+            observer: o,
+            spawn,
+        };
         game.signal_board_changed();
         game
     }
@@ -201,22 +246,21 @@ impl<O> Game<TileNeeded, O>
 where
     O: Observer,
 {
+    /// Spawn `tile` at the position chosen by the game's [`SpawnPolicy`].
     #[must_use]
-    pub fn place_tile(self, tile: BasicTile) -> Either<Game<TileFloating, O>, Game<Over, O>> {
-        let (_, height) = tile.dimensions();
-        let tile = DisplacedTile::new(RotatedTile::new(tile)).displace_by(
-            ((BOARD_COLS >> 1) + 1).try_into().unwrap(), // >> 1 == / 2
-            (BOARD_ROWS - height + 1).try_into().unwrap(),
-        );
+    pub fn place_tile(mut self, tile: BasicTile) -> Either<Game<TileFloating, O>, Game<Over, O>> {
+        let tile = self.spawn.spawn(&self.s.board, tile);
         if self.s.board.is_position_valid(&tile) {
             Either::Left(Game::new_with_signal(
                 TileFloating::new(tile, self.s.board),
                 self.observer,
+                self.spawn,
             ))
         } else {
             Either::Right(Game {
                 s: Over::new(self.s.board),
                 observer: self.observer,
+                spawn: self.spawn,
             })
         }
     }
@@ -250,22 +294,61 @@ where
 {
     #[must_use]
     pub fn descend_tile(self) -> Either<Game<TileFloating, O>, Game<ProcessRows, O>> {
-        let candidate = self.s.tile.clone().displace_by(0, -1);
-
-        if self.s.board.is_position_valid(&candidate) {
+        if let Ok(candidate) = self.check_move(Move::Descend) {
             Either::Left(Game::new_with_signal(
                 TileFloating::new(candidate, self.s.board),
                 self.observer,
+                self.spawn,
             ))
         } else {
             let board = self.s.board.freeze_tile(self.s.tile).unwrap();
             Either::Right(Game::new_with_signal(
                 ProcessRows::new(board),
                 self.observer,
+                self.spawn,
             ))
         }
     }
 
+    /// Board the tile is floating above, not including the tile itself
+    #[must_use]
+    pub fn board(&self) -> &Board<TakesTile> {
+        &self.s.board
+    }
+
+    /// Current position of the floating tile
+    #[must_use]
+    pub fn tile(&self) -> &Placement {
+        &self.s.tile
+    }
+
+    /// Kind of the floating tile
+    #[must_use]
+    pub fn tile_kind(&self) -> &BasicTile {
+        self.s.tile.tile().tile()
+    }
+
+    /// Counter-clockwise rotation of the floating tile, starting at [`Angle::Zero`] on spawn
+    /// unless the [`SpawnPolicy`] says otherwise.
+    #[must_use]
+    pub fn tile_angle(&self) -> &Angle {
+        self.s.tile.tile().angle()
+    }
+
+    /// The returned row is counted 0-indexed from the bottom, see [`tile_column`](Self::tile_column).
+    #[must_use]
+    pub fn tile_row(&self) -> u8 {
+        (*self.s.tile.displ_y() - 1)
+            .try_into()
+            .expect("Row should be in range 0 to 4")
+    }
+
+    /// Shortest sequence of moves taking the floating tile to `target`, see [`find_path`].
+    #[must_use]
+    pub fn path_to(&self, target: &Placement) -> Option<Path> {
+        find_path(&self.s.board, &self.s.tile, target)
+    }
+
     /// The returned column is counted 0-indexed from the left.
     pub fn tile_column(&self) -> u8 {
         (*self.s.tile.displ_x() - 1)
@@ -273,37 +356,66 @@ where
             .expect("Column should be in range 0 to 4")
     }
 
-    fn move_tile_horizontally(&mut self, dir: Direction) -> Result<(), GameError> {
-        let dir: i8 = dir.into();
-        let candidate = self.s.tile.clone().displace_by(dir.into(), 0);
+    /// Position the floating tile would take by `m`, without changing the game.
+    ///
+    /// Returns [`GameError::InvalidMove`] if the position is not valid on the board, in which
+    /// case the corresponding method fails as well, or freezes the tile for [`Move::Descend`].
+    pub fn check_move(&self, m: Move) -> Result<Placement, GameError> {
+        let tile = self.s.tile.clone();
+        let candidate = match m {
+            Move::Left => tile.displace_by(-1, 0),
+            Move::Right => tile.displace_by(1, 0),
+            Move::Rotate => tile.rotate_ccw(),
+            Move::Descend => tile.displace_by(0, -1),
+        };
 
         if self.s.board.is_position_valid(&candidate) {
-            self.s.tile = candidate;
-            self.signal_board_changed();
-            Ok(())
+            Ok(candidate)
         } else {
             Err(GameError::InvalidMove)
         }
     }
 
+    /// See [`check_move`](Self::check_move).
+    #[must_use]
+    pub fn can_move_left(&self) -> bool {
+        self.check_move(Move::Left).is_ok()
+    }
+
+    /// See [`check_move`](Self::check_move).
+    #[must_use]
+    pub fn can_move_right(&self) -> bool {
+        self.check_move(Move::Right).is_ok()
+    }
+
+    /// See [`check_move`](Self::check_move).
+    #[must_use]
+    pub fn can_rotate(&self) -> bool {
+        self.check_move(Move::Rotate).is_ok()
+    }
+
+    /// Whether [`descend_tile`](Self::descend_tile) moves the tile rather than freezing it.
+    #[must_use]
+    pub fn can_descend(&self) -> bool {
+        self.check_move(Move::Descend).is_ok()
+    }
+
+    fn apply_move(&mut self, m: Move) -> Result<(), GameError> {
+        self.s.tile = self.check_move(m)?;
+        self.signal_board_changed();
+        Ok(())
+    }
+
     pub fn move_tile_right(&mut self) -> Result<(), GameError> {
-        self.move_tile_horizontally(Direction::Right)
+        self.apply_move(Move::Right)
     }
 
     pub fn move_tile_left(&mut self) -> Result<(), GameError> {
-        self.move_tile_horizontally(Direction::Left)
+        self.apply_move(Move::Left)
     }
 
     pub fn rotate_tile(&mut self) -> Result<(), GameError> {
-        let candidate = self.s.tile.clone().rotate_ccw();
-
-        if self.s.board.is_position_valid(&candidate) {
-            self.s.tile = candidate;
-            self.signal_board_changed();
-            Ok(())
-        } else {
-            Err(GameError::InvalidMove)
-        }
+        self.apply_move(Move::Rotate)
     }
 }
 
@@ -313,18 +425,105 @@ where
 {
     #[must_use]
     pub fn process_row(self) -> Either<Game<ProcessRows, O>, Game<TileNeeded, O>> {
-        match self.s.board.process_row() {
+        let board = self.s.board.process_row();
+        Self::advance(board, self.observer, self.spawn)
+    }
+
+    /// Like [`Game::process_row`], but play `animation` on fully populated rows, signalling each
+    /// frame to the observer, see [`Board::process_row_animated`].
+    #[must_use]
+    pub fn process_row_animated(
+        self,
+        animation: RowAnimation,
+    ) -> Either<Game<ProcessRows, O>, Game<TileNeeded, O>> {
+        let board = self.s.board.process_row_animated(animation);
+        Self::advance(board, self.observer, self.spawn)
+    }
+
+    /// See [`Board::chain`].
+    #[must_use]
+    pub fn chain(&self) -> usize {
+        self.s.board.chain()
+    }
+
+    /// See [`Board::report`].
+    #[must_use]
+    pub fn report(&self) -> &ClearReport {
+        self.s.board.report()
+    }
+
+    /// Process all rows, signalling each step to the observer, see [`Board::process_all_rows`].
+    #[must_use]
+    pub fn process_all_rows(mut self) -> (Game<TileNeeded, O>, ClearReport) {
+        loop {
+            self = match self.process_row() {
+                Either::Left(game) => game,
+                Either::Right(game) => {
+                    let report = game.last_clear().clone();
+                    return (game, report);
+                }
+            }
+        }
+    }
+
+    /// Process all rows in one go, signalling only the resulting state to the observer, see
+    /// [`Board::process_rows_instantly`].
+    #[must_use]
+    pub fn process_rows_instantly(self) -> (Game<TileNeeded, O>, ClearReport) {
+        let (board, report) = self.s.board.process_rows_instantly();
+        (
+            Game::new_with_signal(TileNeeded::new(board), self.observer, self.spawn),
+            report,
+        )
+    }
+
+    fn advance(
+        board: Either<Board<BoardProcesses>, Board<TakesTile>>,
+        observer: Option<O>,
+        spawn: SpawnPolicy,
+    ) -> Either<Game<ProcessRows, O>, Game<TileNeeded, O>> {
+        match board {
             Either::Left(board) => Either::Left(Game::new_with_signal(
                 ProcessRows::new(board),
-                self.observer,
+                observer,
+                spawn,
+            )),
+            Either::Right(board) => Either::Right(Game::new_with_signal(
+                TileNeeded::new(board),
+                observer,
+                spawn,
             )),
-            Either::Right(board) => {
-                Either::Right(Game::new_with_signal(TileNeeded::new(board), self.observer))
-            }
         }
     }
 }
 
+impl<S, O> Game<S, O>
+where
+    S: Pausable,
+    O: Observer,
+{
+    /// Pause the game, see [`Game::resume`].
+    ///
+    /// While paused, the game reports [`PAUSE_ICON`] as active raster and hides the board.
+    #[must_use]
+    pub fn pause(self) -> Game<Paused<S>, O> {
+        Game::new_with_signal(Paused { previous: self.s }, self.observer, self.spawn)
+    }
+}
+
+impl<S, O> Game<Paused<S>, O>
+where
+    S: Pausable,
+    O: Observer,
+    Game<S, O>: SignalSource,
+{
+    /// Resume the game in exactly the state it has been paused in.
+    #[must_use]
+    pub fn resume(self) -> Game<S, O> {
+        Game::new_with_signal(self.s.previous, self.observer, self.spawn)
+    }
+}
+
 impl<O> Rasterization<Passive> for Game<TileNeeded, O> {
     fn rasterize_buf(&self, out: &mut Grid) {
         self.s.board.rasterize_buf(out);
@@ -363,3 +562,15 @@ impl<O> Rasterization<Passive> for Game<Over, O> {
         self.s.board.rasterize_buf(out);
     }
 }
+
+impl<S, O> Rasterization<Passive> for Game<Paused<S>, O> {
+    fn rasterize_buf(&self, out: &mut Grid) {
+        *out = Grid::default();
+    }
+}
+
+impl<S, O> Rasterization<Active> for Game<Paused<S>, O> {
+    fn rasterize_buf(&self, out: &mut Grid) {
+        *out = PAUSE_ICON;
+    }
+}
@@ -0,0 +1,592 @@
+//! Plain text format for puzzles and level packs.
+//!
+//! A level consists of a board drawn the way [`Grid`]'s `Display` implementation draws it,
+//! followed by a `tiles` line, a `goal` line and an optional `par` line (in any order):
+//!
+//! ```text
+//! +-----+
+//! |     |
+//! |     |
+//! |     |
+//! |x    |
+//! |xx xx|
+//! +-----+
+//! tiles: square line diagonal
+//! goal: clear-rows 1
+//! par: 12
+//! ```
+//!
+//! Valid tiles are `square`, `line` and `diagonal`, valid goals are `clear-rows <n>`,
+//! `empty-board` and `survive <n>`.
+//! The par is the number of moves the level is expected to be solved in, see
+//! [`Attempt::moves`](crate::gameplay::puzzle::Attempt::moves).
+//! A level pack holds several levels separated by empty lines.
+//! Lines starting with `#` are comments and may appear between levels.
+
+use crate::{
+    gameplay::{
+        board::Board,
+        puzzle::{Goal, Puzzle, PuzzleError, MAX_PUZZLE_TILES},
+    },
+    geometry::{
+        grid::{parse_border, parse_row, Grid},
+        tile::BasicTile,
+    },
+};
+use core::{
+    fmt::{Display, Write},
+    iter::{Enumerate, Peekable},
+    str::Lines,
+};
+
+const COMMENT: char = '#';
+
+/// Tile sequence of a [`Level`].
+pub type Tiles = heapless::Vec<BasicTile, MAX_PUZZLE_TILES>;
+
+/// Puzzle definition as read from text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Level {
+    pub board: Grid,
+    pub tiles: Tiles,
+    pub goal: Goal,
+    pub par: Option<usize>,
+}
+
+impl TryFrom<Level> for Puzzle {
+    type Error = PuzzleError;
+
+    fn try_from(value: Level) -> Result<Self, Self::Error> {
+        let board = Board::from_grid(value.board).map_err(PuzzleError::InvalidBoard)?;
+        let puzzle = Puzzle::new(board, &value.tiles, value.goal)?;
+        Ok(match value.par {
+            Some(par) => puzzle.with_par(par),
+            None => puzzle,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input ended before the level was complete
+    UnexpectedEnd,
+    /// Found something else than expected
+    UnexpectedChar(char),
+    /// The line is expected to end at the reported position
+    ExpectedEndOfLine,
+    /// Unknown key in front of a `:`
+    UnknownKey,
+    /// The line lacks the `:` separating key and value
+    MissingColon,
+    /// The same key has been given twice
+    DuplicateKey,
+    /// The level lacks a `tiles` line
+    MissingTiles,
+    /// The level lacks a `goal` line
+    MissingGoal,
+    /// The word is not one of `square`, `line` or `diagonal`
+    UnknownTile,
+    /// The tile sequence exceeds [`MAX_PUZZLE_TILES`]
+    TooManyTiles,
+    /// The word is not one of `clear-rows`, `empty-board` or `survive`
+    UnknownGoal,
+    /// The word is not a non-negative number
+    InvalidNumber,
+}
+
+/// Error encountered while parsing, located by 1-indexed line and column.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    fn new(line: usize, column: usize, kind: ParseErrorKind) -> Self {
+        Self { line, column, kind }
+    }
+}
+
+/// Line-wise view on the input keeping track of line numbers.
+struct Cursor<'a> {
+    lines: Peekable<Enumerate<Lines<'a>>>,
+    line: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            lines: input.lines().enumerate().peekable(),
+            line: 0,
+        }
+    }
+
+    /// Next line alongside its 1-indexed line number.
+    fn next(&mut self) -> Result<(usize, &'a str), ParseError> {
+        match self.lines.next() {
+            Some((idx, line)) => {
+                self.line = idx + 1;
+                Ok((self.line, line))
+            }
+            None => Err(ParseError::new(
+                self.line + 1,
+                1,
+                ParseErrorKind::UnexpectedEnd,
+            )),
+        }
+    }
+
+    /// Skip empty lines and comments, returning whether there is any line left.
+    fn skip_blank(&mut self) -> bool {
+        while let Some((_, line)) = self.lines.peek() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(COMMENT) {
+                self.lines.next();
+            } else {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Whether the next line terminates the current level, i.e. is empty or a comment.
+    fn at_level_end(&mut self) -> bool {
+        self.lines.peek().is_none_or(|(_, line)| {
+            let line = line.trim();
+            line.is_empty() || line.starts_with(COMMENT)
+        })
+    }
+}
+
+/// Words of `line` alongside their 0-indexed column.
+fn words(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split(' ')
+        .scan(0, |column, word| {
+            let start = *column;
+            *column += word.len() + 1;
+            Some((start, word))
+        })
+        .filter(|(_, word)| !word.is_empty())
+}
+
+/// Translate the index of an offending byte in `line` into a [`ParseError`].
+fn offending(line_no: usize, line: &str, idx: usize) -> ParseError {
+    let kind = match line.get(idx..).and_then(|rest| rest.chars().next()) {
+        Some(c) => ParseErrorKind::UnexpectedChar(c),
+        None => ParseErrorKind::UnexpectedEnd,
+    };
+    ParseError::new(line_no, idx + 1, kind)
+}
+
+fn parse_border_line(cursor: &mut Cursor<'_>) -> Result<(), ParseError> {
+    let (line_no, line) = cursor.next()?;
+    parse_border(line.as_bytes(), 0, line.len(), Grid::NUM_COLS)
+        .map_err(|idx| offending(line_no, line, idx))
+}
+
+fn parse_board(cursor: &mut Cursor<'_>) -> Result<Grid, ParseError> {
+    parse_border_line(cursor)?;
+
+    let mut grid = Grid::default();
+    // Rows are drawn top to bottom
+    for row in (0..Grid::NUM_ROWS).rev() {
+        let (line_no, line) = cursor.next()?;
+        let bits = parse_row(line.as_bytes(), 0, line.len(), Grid::NUM_COLS)
+            .map_err(|idx| offending(line_no, line, idx))?;
+
+        for col in (0..Grid::NUM_COLS).filter(|col| bits & (1 << col) != 0) {
+            grid = grid
+                .set_element(row, col)
+                .expect("Hardcoded range should be valid");
+        }
+    }
+
+    parse_border_line(cursor)?;
+
+    Ok(grid)
+}
+
+fn parse_number(line_no: usize, column: usize, word: &str) -> Result<usize, ParseError> {
+    word.parse()
+        .map_err(|_| ParseError::new(line_no, column + 1, ParseErrorKind::InvalidNumber))
+}
+
+fn parse_tiles(line_no: usize, value: &str, offset: usize) -> Result<Tiles, ParseError> {
+    let mut tiles = Tiles::new();
+
+    for (column, word) in words(value) {
+        let column = offset + column;
+        let tile = match word {
+            "square" => BasicTile::Square,
+            "line" => BasicTile::Line,
+            "diagonal" => BasicTile::Diagonal,
+            _ => {
+                return Err(ParseError::new(
+                    line_no,
+                    column + 1,
+                    ParseErrorKind::UnknownTile,
+                ))
+            }
+        };
+        tiles
+            .push(tile)
+            .map_err(|_| ParseError::new(line_no, column + 1, ParseErrorKind::TooManyTiles))?;
+    }
+
+    Ok(tiles)
+}
+
+fn parse_goal(line_no: usize, value: &str, offset: usize) -> Result<Goal, ParseError> {
+    let mut words = words(value).map(|(column, word)| (offset + column, word));
+
+    let (column, name) = words.next().ok_or(ParseError::new(
+        line_no,
+        offset + value.len() + 1,
+        ParseErrorKind::UnexpectedEnd,
+    ))?;
+
+    let mut argument = || {
+        words
+            .next()
+            .ok_or(ParseError::new(
+                line_no,
+                offset + value.len() + 1,
+                ParseErrorKind::UnexpectedEnd,
+            ))
+            .and_then(|(column, word)| parse_number(line_no, column, word))
+    };
+
+    let goal = match name {
+        "clear-rows" => Goal::ClearRows(argument()?),
+        "empty-board" => Goal::EmptyBoard,
+        "survive" => Goal::Survive(argument()?),
+        _ => {
+            return Err(ParseError::new(
+                line_no,
+                column + 1,
+                ParseErrorKind::UnknownGoal,
+            ))
+        }
+    };
+
+    if let Some((column, _)) = words.next() {
+        return Err(ParseError::new(
+            line_no,
+            column + 1,
+            ParseErrorKind::ExpectedEndOfLine,
+        ));
+    }
+
+    Ok(goal)
+}
+
+fn parse_par(line_no: usize, value: &str, offset: usize) -> Result<usize, ParseError> {
+    let mut words = words(value).map(|(column, word)| (offset + column, word));
+
+    let (column, word) = words.next().ok_or(ParseError::new(
+        line_no,
+        offset + value.len() + 1,
+        ParseErrorKind::UnexpectedEnd,
+    ))?;
+    let par = parse_number(line_no, column, word)?;
+
+    if let Some((column, _)) = words.next() {
+        return Err(ParseError::new(
+            line_no,
+            column + 1,
+            ParseErrorKind::ExpectedEndOfLine,
+        ));
+    }
+
+    Ok(par)
+}
+
+fn parse_level_at(cursor: &mut Cursor<'_>) -> Result<Level, ParseError> {
+    let board = parse_board(cursor)?;
+
+    let mut tiles = None;
+    let mut goal = None;
+    let mut par = None;
+
+    while !cursor.at_level_end() {
+        let (line_no, line) = cursor.next()?;
+
+        let Some((key, value)) = line.split_once(':') else {
+            return Err(ParseError::new(
+                line_no,
+                line.len() + 1,
+                ParseErrorKind::MissingColon,
+            ));
+        };
+        let offset = key.len() + 1;
+
+        match key.trim() {
+            "tiles" if tiles.is_none() => tiles = Some(parse_tiles(line_no, value, offset)?),
+            "goal" if goal.is_none() => goal = Some(parse_goal(line_no, value, offset)?),
+            "par" if par.is_none() => par = Some(parse_par(line_no, value, offset)?),
+            "tiles" | "goal" | "par" => {
+                return Err(ParseError::new(line_no, 1, ParseErrorKind::DuplicateKey))
+            }
+            _ => return Err(ParseError::new(line_no, 1, ParseErrorKind::UnknownKey)),
+        }
+    }
+
+    let missing = |kind| ParseError::new(cursor.line + 1, 1, kind);
+    Ok(Level {
+        board,
+        tiles: tiles.ok_or_else(|| missing(ParseErrorKind::MissingTiles))?,
+        goal: goal.ok_or_else(|| missing(ParseErrorKind::MissingGoal))?,
+        par,
+    })
+}
+
+/// Parse a single level.
+///
+/// # Errors
+///
+/// Returns the position of the first syntax error, if any. Blank lines and comments before and
+/// after the level are ignored, but anything else is reported.
+pub fn parse_level(input: &str) -> Result<Level, ParseError> {
+    let mut cursor = Cursor::new(input);
+
+    cursor.skip_blank();
+    let level = parse_level_at(&mut cursor)?;
+
+    if cursor.skip_blank() {
+        let (line_no, line) = cursor.next()?;
+        let column = line.len() - line.trim_start().len() + 1;
+        return Err(ParseError::new(
+            line_no,
+            column,
+            ParseErrorKind::UnexpectedChar(line.trim_start().chars().next().unwrap_or(' ')),
+        ));
+    }
+
+    Ok(level)
+}
+
+/// Parse a level pack, yielding one result per level.
+///
+/// Parsing stops after the first error.
+#[must_use]
+pub fn parse_pack(input: &str) -> LevelPack<'_> {
+    LevelPack {
+        cursor: Cursor::new(input),
+        failed: false,
+    }
+}
+
+/// Iterator over the levels of a level pack, see [`parse_pack`].
+pub struct LevelPack<'a> {
+    cursor: Cursor<'a>,
+    failed: bool,
+}
+
+impl Iterator for LevelPack<'_> {
+    type Item = Result<Level, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || !self.cursor.skip_blank() {
+            return None;
+        }
+
+        let level = parse_level_at(&mut self.cursor);
+        self.failed = level.is_err();
+        Some(level)
+    }
+}
+
+fn tile_name(tile: &BasicTile) -> &'static str {
+    match tile {
+        BasicTile::Square => "square",
+        BasicTile::Line => "line",
+        BasicTile::Diagonal => "diagonal",
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.board.fmt(f)?;
+
+        f.write_str("tiles:")?;
+        for tile in &self.tiles {
+            f.write_char(' ')?;
+            f.write_str(tile_name(tile))?;
+        }
+        f.write_char('\n')?;
+
+        match self.goal {
+            Goal::ClearRows(num_rows) => writeln!(f, "goal: clear-rows {num_rows}")?,
+            Goal::EmptyBoard => writeln!(f, "goal: empty-board")?,
+            Goal::Survive(num_tiles) => writeln!(f, "goal: survive {num_tiles}")?,
+        }
+
+        match self.par {
+            Some(par) => writeln!(f, "par: {par}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Write `levels` as a level pack to `out`.
+///
+/// # Errors
+///
+/// Forwards errors of `out`.
+pub fn write_pack<'a, W, I>(levels: I, out: &mut W) -> core::fmt::Result
+where
+    W: Write,
+    I: IntoIterator<Item = &'a Level>,
+{
+    for (idx, level) in levels.into_iter().enumerate() {
+        if idx != 0 {
+            out.write_char('\n')?;
+        }
+        write!(out, "{level}")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEVEL: &str = "\
++-----+
+|     |
+|     |
+|     |
+|x    |
+|xx xx|
++-----+
+tiles: square line diagonal
+goal: clear-rows 1
+par: 12
+";
+
+    fn level() -> Level {
+        Level {
+            board: Grid::from([
+                [true, true, false, true, true],
+                [true, false, false, false, false],
+                [false; Grid::NUM_COLS],
+                [false; Grid::NUM_COLS],
+                [false; Grid::NUM_COLS],
+            ]),
+            tiles: Tiles::from_slice(&[BasicTile::Square, BasicTile::Line, BasicTile::Diagonal])
+                .unwrap(),
+            goal: Goal::ClearRows(1),
+            par: Some(12),
+        }
+    }
+
+    fn error(input: &str) -> (usize, usize, ParseErrorKind) {
+        let err = parse_level(input).unwrap_err();
+        (err.line, err.column, err.kind)
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_level(LEVEL), Ok(level()));
+    }
+
+    #[test]
+    fn board_matches_display() {
+        let mut text = heapless::String::<64>::new();
+        write!(text, "{}", level().board).unwrap();
+
+        assert!(LEVEL.starts_with(text.as_str()));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut text = heapless::String::<256>::new();
+        write!(text, "{}", level()).unwrap();
+
+        assert_eq!(text, LEVEL);
+        assert_eq!(parse_level(&text), Ok(level()));
+    }
+
+    #[test]
+    fn keys_in_any_order() {
+        let input = LEVEL.replace(
+            "tiles: square line diagonal\ngoal: clear-rows 1\n",
+            "goal: clear-rows 1\ntiles: square line diagonal\n",
+        );
+
+        assert_eq!(parse_level(&input), Ok(level()));
+    }
+
+    #[test]
+    fn par_is_optional() {
+        let mut no_par = level();
+        no_par.par = None;
+
+        assert_eq!(
+            parse_level(&LEVEL.replace("par: 12\n", "")),
+            Ok(no_par.clone())
+        );
+        assert_eq!(Puzzle::try_from(no_par).unwrap().par(), None);
+        assert_eq!(Puzzle::try_from(level()).unwrap().par(), Some(12));
+        assert_eq!(
+            error(&LEVEL.replace("par: 12", "par: 12 13")),
+            (10, 9, ParseErrorKind::ExpectedEndOfLine)
+        );
+    }
+
+    #[test]
+    fn pack() {
+        let mut text = heapless::String::<512>::new();
+        write_pack(&[level(), level()], &mut text).unwrap();
+        let text = text.replace("+-----+\n|", "# comment\n+-----+\n|");
+        // comments may directly follow a level as well
+        let text = text.replace("par: 12\n\n", "par: 12\n# comment\n");
+
+        let mut pack = parse_pack(&text);
+        assert_eq!(pack.next(), Some(Ok(level())));
+        assert_eq!(pack.next(), Some(Ok(level())));
+        assert_eq!(pack.next(), None);
+    }
+
+    #[test]
+    fn errors_are_located() {
+        assert_eq!(
+            error(&LEVEL.replace("|xx xx|", "|xx-xx|")),
+            (6, 4, ParseErrorKind::UnexpectedChar('-'))
+        );
+        assert_eq!(
+            error(&LEVEL.replace("|x    |", "|x    ")),
+            (5, 7, ParseErrorKind::UnexpectedEnd)
+        );
+        assert_eq!(
+            error(&LEVEL.replace("line", "lime")),
+            (8, 15, ParseErrorKind::UnknownTile)
+        );
+        assert_eq!(
+            error(&LEVEL.replace("clear-rows 1", "clear-rows one")),
+            (9, 18, ParseErrorKind::InvalidNumber)
+        );
+        assert_eq!(
+            error(&LEVEL.replace("goal: clear-rows 1\n", "")),
+            (10, 1, ParseErrorKind::MissingGoal)
+        );
+        assert_eq!(error(&LEVEL[..44]), (6, 5, ParseErrorKind::UnexpectedEnd));
+        assert_eq!(
+            error(&LEVEL.replace("par: 12", "par 12")),
+            (10, 7, ParseErrorKind::MissingColon)
+        );
+    }
+
+    #[test]
+    fn into_puzzle() {
+        assert!(Puzzle::try_from(level()).is_ok());
+
+        let mut full_row = level();
+        full_row.board = Grid::R0;
+        assert!(matches!(
+            Puzzle::try_from(full_row),
+            Err(PuzzleError::InvalidBoard(_))
+        ));
+    }
+}
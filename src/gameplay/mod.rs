@@ -1,3 +1,8 @@
 pub mod board;
 pub mod game;
+pub mod level;
+pub mod mode;
+pub mod placement;
+pub mod puzzle;
 pub mod raster;
+pub mod spawn;
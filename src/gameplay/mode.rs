@@ -0,0 +1,340 @@
+use crate::gameplay::{
+    board::BOARD_ROWS,
+    game::{Game, TileNeeded},
+};
+
+/// Number of rows to clear to advance to the next level in [`Mode::Marathon`].
+pub const ROWS_PER_LEVEL: usize = 10;
+
+/// Points awarded for clearing one, two, ... rows with a single tile (at level 1).
+///
+/// Tiles are at most two rows high, but
+/// [`Gravity::Sticky`](crate::gameplay::board::Gravity::Sticky) and
+/// [`Gravity::Cascade`](crate::gameplay::board::Gravity::Cascade) may clear further rows in
+/// chain reactions, up to the whole board.
+pub const POINTS_PER_CLEAR: [u32; BOARD_ROWS] = [100, 300, 500, 800, 1200];
+
+/// Ruleset on top of a [`Game`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Endless play, advancing a level every [`ROWS_PER_LEVEL`] cleared rows
+    Marathon,
+    /// Clear the given number of rows as fast as possible
+    Sprint(usize),
+    /// Score as many points as possible within the given number of ticks
+    Ultra(u32),
+}
+
+/// How a [`Session`] ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The mode's goal has been met (a sprint's rows have been cleared or an ultra's ticks have
+    /// elapsed)
+    Completed,
+    /// The game has been over before meeting the mode's goal
+    ToppedOut,
+}
+
+/// Result of a finished [`Session`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Summary {
+    pub mode: Mode,
+    pub outcome: Outcome,
+    pub ticks: u32,
+    pub cleared_rows: usize,
+    pub score: u32,
+    pub level: usize,
+    pub tiles: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Running,
+    Finished(Summary),
+}
+
+/// Bookkeeping of a game played in a specific [`Mode`].
+///
+/// Call [`Session::tick`] on each tick of the game's clock, [`Session::rows_processed`] each
+/// time the game transitions from [`ProcessRows`](crate::gameplay::game::ProcessRows) to
+/// [`TileNeeded`] and [`Session::game_over`] once the game is over.
+/// As soon as any of these report [`Status::Finished`], the session is over and further calls
+/// keep reporting the same summary.
+#[derive(Clone, Debug)]
+pub struct Session {
+    mode: Mode,
+    ticks: u32,
+    cleared_rows: usize,
+    score: u32,
+    tiles: usize,
+    outcome: Option<Outcome>,
+}
+
+impl Session {
+    #[must_use]
+    pub fn new(mode: Mode) -> Self {
+        Self {
+            mode,
+            ticks: 0,
+            cleared_rows: 0,
+            score: 0,
+            tiles: 0,
+            outcome: None,
+        }
+    }
+
+    /// Advance the session's clock by one tick.
+    pub fn tick(&mut self) -> Status {
+        if self.outcome.is_none() {
+            self.ticks = self.ticks.saturating_add(1);
+
+            if let Mode::Ultra(budget) = self.mode {
+                if self.ticks >= budget {
+                    self.outcome = Some(Outcome::Completed);
+                }
+            }
+        }
+        self.status()
+    }
+
+    /// Account for a tile having been dropped on `game`, which has just finished processing
+    /// rows.
+    pub fn rows_processed<O>(&mut self, game: &Game<TileNeeded, O>) -> Status {
+        if self.outcome.is_none() {
+            let cleared = game.last_clear().count();
+            let level: u32 = self.level().try_into().unwrap_or(u32::MAX);
+
+            self.tiles += 1;
+            self.cleared_rows += cleared;
+            if let Some(points) = cleared
+                .checked_sub(1)
+                .and_then(|idx| POINTS_PER_CLEAR.get(idx))
+            {
+                self.score = self.score.saturating_add(points.saturating_mul(level));
+            }
+
+            if let Mode::Sprint(num_rows) = self.mode {
+                if self.cleared_rows >= num_rows {
+                    self.outcome = Some(Outcome::Completed);
+                }
+            }
+        }
+        self.status()
+    }
+
+    /// Account for the game being over.
+    pub fn game_over(&mut self) -> Summary {
+        let outcome = *self.outcome.get_or_insert(Outcome::ToppedOut);
+        self.summary(outcome)
+    }
+
+    #[must_use]
+    pub fn status(&self) -> Status {
+        match self.outcome {
+            None => Status::Running,
+            Some(outcome) => Status::Finished(self.summary(outcome)),
+        }
+    }
+
+    fn summary(&self, outcome: Outcome) -> Summary {
+        Summary {
+            mode: self.mode.clone(),
+            outcome,
+            ticks: self.ticks,
+            cleared_rows: self.cleared_rows,
+            score: self.score,
+            level: self.level(),
+            tiles: self.tiles,
+        }
+    }
+
+    #[must_use]
+    pub fn mode(&self) -> &Mode {
+        &self.mode
+    }
+
+    #[must_use]
+    pub fn ticks(&self) -> u32 {
+        self.ticks
+    }
+
+    #[must_use]
+    pub fn cleared_rows(&self) -> usize {
+        self.cleared_rows
+    }
+
+    #[must_use]
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    /// Current level, starting at 1.
+    ///
+    /// Only [`Mode::Marathon`] progresses through levels, all other modes stay at level 1.
+    #[must_use]
+    pub fn level(&self) -> usize {
+        match self.mode {
+            Mode::Marathon => 1 + self.cleared_rows / ROWS_PER_LEVEL,
+            Mode::Sprint(_) | Mode::Ultra(_) => 1,
+        }
+    }
+
+    /// Suggested number of ticks between two consecutive calls to `descend_tile` at the current
+    /// level, given the number of ticks at level 1.
+    ///
+    /// The interval shrinks by an eighth per level, but never drops below one tick.
+    #[must_use]
+    pub fn descend_interval(&self, initial: u32) -> u32 {
+        let mut interval = initial;
+        for _ in 1..self.level() {
+            interval -= interval / 8;
+        }
+        interval.max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        gameplay::{
+            board::{Board, Gravity},
+            game::NoopObserver,
+        },
+        geometry::tile::BasicTile,
+        grid,
+    };
+    use either::Either;
+
+    /// Move the tile as far right as possible and drop it.
+    fn drop_right(
+        game: Game<TileNeeded, NoopObserver>,
+        tile: BasicTile,
+    ) -> Game<TileNeeded, NoopObserver> {
+        let Either::Left(mut game) = game.place_tile(tile) else {
+            panic!("Game should not be over")
+        };
+        while game.move_tile_right().is_ok() {}
+
+        loop {
+            game = match game.descend_tile() {
+                Either::Left(game) => game,
+                Either::Right(game) => return game.process_all_rows().0,
+            }
+        }
+    }
+
+    fn two_gaps() -> Game<TileNeeded, NoopObserver> {
+        let board = Board::from_grid(grid!(
+            "
+            +-----+
+            |     |
+            |     |
+            |     |
+            |xxxx |
+            |xxxx |
+            +-----+
+            "
+        ))
+        .unwrap();
+        Game::with_board(board)
+    }
+
+    #[test]
+    fn sprint() {
+        let game = two_gaps();
+        let mut session = Session::new(Mode::Sprint(2));
+
+        for _ in 0..5 {
+            assert_eq!(session.tick(), Status::Running);
+        }
+        let game = drop_right(game, BasicTile::Square);
+        assert_eq!(session.rows_processed(&game), Status::Running);
+        let game = drop_right(game, BasicTile::Square);
+
+        let Status::Finished(summary) = session.rows_processed(&game) else {
+            panic!("Sprint should have finished");
+        };
+        assert_eq!(summary.outcome, Outcome::Completed);
+        assert_eq!(summary.ticks, 5);
+        assert_eq!(summary.cleared_rows, 2);
+        assert_eq!(summary.tiles, 2);
+        assert_eq!(summary.score, 2 * POINTS_PER_CLEAR[0]);
+    }
+
+    #[test]
+    fn ultra() {
+        let game = two_gaps();
+        let mut session = Session::new(Mode::Ultra(3));
+
+        let game = drop_right(game, BasicTile::Line);
+        assert_eq!(session.rows_processed(&game), Status::Running);
+        assert_eq!(session.tick(), Status::Running);
+        assert_eq!(session.tick(), Status::Running);
+
+        let Status::Finished(summary) = session.tick() else {
+            panic!("Ultra should have finished");
+        };
+        assert_eq!(summary.outcome, Outcome::Completed);
+        assert_eq!(summary.score, POINTS_PER_CLEAR[1]);
+
+        // Finished sessions are not updated anymore
+        assert_eq!(session.tick(), Status::Finished(summary.clone()));
+        assert_eq!(session.game_over(), summary);
+    }
+
+    #[test]
+    fn chain_clears_score() {
+        let board = Board::from_grid(grid!(
+            "
+            +-----+
+            |     |
+            | x   |
+            |xxxx |
+            |xxxx |
+            |x xxx|
+            +-----+
+            "
+        ))
+        .unwrap()
+        .with_gravity(Gravity::Cascade);
+        let mut session = Session::new(Mode::Marathon);
+
+        let game = drop_right(Game::with_board(board), BasicTile::Line);
+        assert_eq!(session.rows_processed(&game), Status::Running);
+        assert_eq!(session.cleared_rows(), 3);
+        assert_eq!(session.score(), POINTS_PER_CLEAR[2]);
+    }
+
+    #[test]
+    fn marathon_ends_by_game_over() {
+        let game = Game::<_, NoopObserver>::new();
+        let mut session = Session::new(Mode::Marathon);
+
+        let game = drop_right(game, BasicTile::Line);
+        assert_eq!(session.rows_processed(&game), Status::Running);
+
+        let summary = session.game_over();
+        assert_eq!(summary.outcome, Outcome::ToppedOut);
+        assert_eq!(summary.level, 1);
+    }
+
+    #[test]
+    fn ticks_saturate() {
+        let mut session = Session::new(Mode::Marathon);
+        session.ticks = u32::MAX;
+
+        assert_eq!(session.tick(), Status::Running);
+        assert_eq!(session.ticks(), u32::MAX);
+    }
+
+    #[test]
+    fn marathon_levels() {
+        let mut session = Session::new(Mode::Marathon);
+        session.cleared_rows = 2 * ROWS_PER_LEVEL;
+
+        assert_eq!(session.level(), 3);
+        assert_eq!(session.descend_interval(64), 49);
+        assert_eq!(session.descend_interval(1), 1);
+    }
+}
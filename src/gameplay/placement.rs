@@ -0,0 +1,439 @@
+use crate::{
+    gameplay::{
+        board::{Board, TakesTile},
+        spawn::SpawnPolicy,
+    },
+    geometry::{
+        grid::ExtGrid,
+        tile::{Angle, BasicTile, DisplacedTile, Displacee, RotatedTile, Rotatee},
+    },
+};
+
+/// A tile at a specific angle and displacement, as floated by
+/// [`Game<TileFloating, _>`](crate::gameplay::game::Game).
+pub type Placement = DisplacedTile<RotatedTile<BasicTile>>;
+
+/// Offsets of the cells of any `BasicTile` from the tile's origin in either direction,
+/// regardless of the tile's angle.
+pub(crate) const OFFSETS: [i32; 3] = [-1, 0, 1];
+
+// Every valid placement has all its cells inside the board. Since its cells are within `OFFSETS`
+// of the tile's origin, the displacement of any valid placement lies within the `ExtGrid`'s
+// extent.
+const NUM_ANGLES: usize = 4;
+const NUM_DISPL_X: usize = ExtGrid::NUM_COLS;
+const NUM_DISPL_Y: usize = ExtGrid::NUM_ROWS;
+const NUM_STATES: usize = NUM_ANGLES * NUM_DISPL_X * NUM_DISPL_Y;
+
+/// Single step a floating tile can take.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Move {
+    /// See [`Game::move_tile_left`](crate::gameplay::game::Game::move_tile_left)
+    Left,
+    /// See [`Game::move_tile_right`](crate::gameplay::game::Game::move_tile_right)
+    Right,
+    /// See [`Game::rotate_tile`](crate::gameplay::game::Game::rotate_tile)
+    Rotate,
+    /// See [`Game::descend_tile`](crate::gameplay::game::Game::descend_tile)
+    Descend,
+}
+
+impl Move {
+    const ALL: [Self; 4] = [Self::Left, Self::Right, Self::Rotate, Self::Descend];
+}
+
+// States are stored as `u8` indices to keep the searches' memory footprint small
+const _: () = assert!(NUM_STATES <= u8::MAX as usize);
+
+/// Angle (as number of counter-clockwise rotations) and displacement of a tile, encoded as its
+/// index among all `NUM_STATES` states.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct TileState(u8);
+
+impl TileState {
+    fn new(rotations: usize, displ_x: usize, displ_y: usize) -> Self {
+        Self::from_idx((rotations * NUM_DISPL_Y + displ_y) * NUM_DISPL_X + displ_x)
+    }
+
+    fn from_idx(idx: usize) -> Self {
+        Self(u8::try_from(idx).expect("Every state index fits into a u8"))
+    }
+
+    fn from_placement(placement: &Placement) -> Option<Self> {
+        let rotations = match placement.tile().angle() {
+            Angle::Zero => 0,
+            Angle::Ninety => 1,
+            Angle::OneEighty => 2,
+            Angle::TwoSeventy => 3,
+        };
+
+        let displ_x = usize::try_from(*placement.displ_x()).ok()?;
+        let displ_y = usize::try_from(*placement.displ_y()).ok()?;
+
+        if displ_x >= NUM_DISPL_X || displ_y >= NUM_DISPL_Y {
+            return None;
+        }
+
+        Some(Self::new(rotations, displ_x, displ_y))
+    }
+
+    fn idx(self) -> usize {
+        self.0.into()
+    }
+
+    fn rotations(self) -> usize {
+        self.idx() / (NUM_DISPL_X * NUM_DISPL_Y)
+    }
+
+    fn displ_x(self) -> usize {
+        self.idx() % NUM_DISPL_X
+    }
+
+    fn displ_y(self) -> usize {
+        (self.idx() / NUM_DISPL_X) % NUM_DISPL_Y
+    }
+
+    /// Apply `m`, returning `None` if the resulting state is out of bounds.
+    fn step(self, m: Move) -> Option<Self> {
+        let (mut rotations, mut displ_x, mut displ_y) =
+            (self.rotations(), self.displ_x(), self.displ_y());
+        match m {
+            Move::Left => displ_x = displ_x.checked_sub(1)?,
+            Move::Right => displ_x += 1,
+            Move::Rotate => rotations = (rotations + 1) % NUM_ANGLES,
+            Move::Descend => displ_y = displ_y.checked_sub(1)?,
+        }
+
+        (displ_x < NUM_DISPL_X).then(|| Self::new(rotations, displ_x, displ_y))
+    }
+
+    /// Undo `m`, i.e. the state `m` has been applied to in order to reach `self`.
+    fn step_back(self, m: Move) -> Self {
+        let (mut rotations, mut displ_x, mut displ_y) =
+            (self.rotations(), self.displ_x(), self.displ_y());
+        match m {
+            Move::Left => displ_x += 1,
+            Move::Right => displ_x -= 1,
+            Move::Rotate => rotations = (rotations + NUM_ANGLES - 1) % NUM_ANGLES,
+            Move::Descend => displ_y += 1,
+        }
+
+        Self::new(rotations, displ_x, displ_y)
+    }
+
+    fn to_placement(self, tile: &BasicTile) -> Placement {
+        let mut rotated = RotatedTile::new(tile.clone());
+        for _ in 0..self.rotations() {
+            rotated = rotated.rotate_ccw();
+        }
+        rotated.displace_by(
+            self.displ_x()
+                .try_into()
+                .expect("Hardcoded range should be valid"),
+            self.displ_y()
+                .try_into()
+                .expect("Hardcoded range should be valid"),
+        )
+    }
+}
+
+/// Sequence of moves as found by [`find_path`].
+pub type Path = heapless::Vec<Move, NUM_STATES>;
+
+/// Fixed-size set of [`TileState`]s.
+#[derive(Clone, Debug, Default)]
+struct StateSet([u64; NUM_STATES.div_ceil(64)]);
+
+impl StateSet {
+    fn insert(&mut self, state: TileState) -> bool {
+        let idx = state.idx();
+        let bit = 1 << (idx % 64);
+        let fresh = self.0[idx / 64] & bit == 0;
+        self.0[idx / 64] |= bit;
+        fresh
+    }
+
+    fn contains(&self, state: TileState) -> bool {
+        let idx = state.idx();
+        self.0[idx / 64] & (1 << (idx % 64)) != 0
+    }
+}
+
+/// Iterator over all final resting placements of a tile, see [`placements`].
+#[derive(Clone, Debug)]
+pub struct Placements {
+    tile: BasicTile,
+    resting: StateSet,
+    next: usize,
+}
+
+impl Iterator for Placements {
+    type Item = Placement;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next < NUM_STATES {
+            let state = TileState::from_idx(self.next);
+            self.next += 1;
+
+            if self.resting.contains(state) {
+                return Some(state.to_placement(&self.tile));
+            }
+        }
+        None
+    }
+}
+
+/// Enumerate every final resting placement of `tile` on `board`, spawned according to
+/// [`SpawnPolicy::default`].
+///
+/// See [`placements_from`] for details.
+#[must_use]
+pub fn placements(board: &Board<TakesTile>, tile: BasicTile) -> Placements {
+    let spawn = SpawnPolicy::default().spawn(board, tile);
+    placements_from(board, &spawn)
+}
+
+/// Enumerate every final resting placement of the tile spawned at `spawn` on `board`.
+///
+/// A placement is considered resting, if the tile cannot descend any further, i.e. the next call
+/// to `descend_tile` freezes it.
+/// Only placements reachable from `spawn` by moving the tile left, right, rotating it and
+/// descending it are enumerated.
+///
+/// Placements are distinguished by angle and displacement, so symmetric tiles may yield several
+/// placements occupying the same cells (e.g. a [`BasicTile::Square`] yields one placement per
+/// angle).
+/// If the spawn position is already blocked, no placement is enumerated.
+#[must_use]
+pub fn placements_from(board: &Board<TakesTile>, spawn: &Placement) -> Placements {
+    let mut resting = StateSet::default();
+    let tile = spawn.tile().tile().clone();
+
+    let spawn = TileState::from_placement(spawn)
+        .filter(|spawn| board.is_position_valid(&spawn.to_placement(&tile)));
+
+    if let Some(spawn) = spawn {
+        let mut visited = StateSet::default();
+        let mut pending = heapless::Vec::<TileState, NUM_STATES>::new();
+
+        visited.insert(spawn);
+        pending
+            .push(spawn)
+            .expect("Each state is visited at most once");
+
+        while let Some(state) = pending.pop() {
+            let mut rests = true;
+
+            for m in Move::ALL {
+                let candidate = state
+                    .step(m)
+                    .filter(|c| board.is_position_valid(&c.to_placement(&tile)));
+
+                let Some(candidate) = candidate else {
+                    continue;
+                };
+
+                if let Move::Descend = m {
+                    rests = false;
+                }
+
+                if visited.insert(candidate) {
+                    pending
+                        .push(candidate)
+                        .expect("Each state is visited at most once");
+                }
+            }
+
+            if rests {
+                resting.insert(state);
+            }
+        }
+    }
+
+    Placements {
+        tile,
+        resting,
+        next: 0,
+    }
+}
+
+/// Find the shortest sequence of moves taking the floating tile `start` to `target` on `board`.
+///
+/// Moves are searched breadth-first over all tile states reachable from `start`, without ever
+/// leaving the set of valid positions.
+/// Hence, replaying the returned moves on a
+/// [`Game<TileFloating, _>`](crate::gameplay::game::Game) floating `start` moves the tile to
+/// `target` without the tile getting frozen along the way.
+///
+/// Returns `None` if `target` is unreachable from `start`, in particular if both refer to
+/// different kinds of tile or if either of them is at an invalid position.
+#[must_use]
+pub fn find_path(board: &Board<TakesTile>, start: &Placement, target: &Placement) -> Option<Path> {
+    let tile = start.tile().tile();
+    if tile != target.tile().tile()
+        || !board.is_position_valid(start)
+        || !board.is_position_valid(target)
+    {
+        return None;
+    }
+
+    let start = TileState::from_placement(start)?;
+    let target = TileState::from_placement(target)?;
+
+    // For each visited state, remember the move it has been reached by, which in turn determines
+    // the state it has been reached from
+    let mut predecessors: [Option<Move>; NUM_STATES] = [None; NUM_STATES];
+    let mut visited = StateSet::default();
+    let mut pending = heapless::Deque::<TileState, NUM_STATES>::new();
+
+    visited.insert(start);
+    pending
+        .push_back(start)
+        .expect("Each state is visited at most once");
+
+    while let Some(state) = pending.pop_front() {
+        if state == target {
+            let mut path = Path::new();
+            let mut current = state;
+
+            while let Some(m) = predecessors[current.idx()] {
+                path.push(m).expect("Each state is visited at most once");
+                current = current.step_back(m);
+            }
+
+            path.reverse();
+            return Some(path);
+        }
+
+        for m in Move::ALL {
+            let candidate = state
+                .step(m)
+                .filter(|c| board.is_position_valid(&c.to_placement(tile)));
+
+            if let Some(candidate) = candidate {
+                if visited.insert(candidate) {
+                    predecessors[candidate.idx()] = Some(m);
+                    pending
+                        .push_back(candidate)
+                        .expect("Each state is visited at most once");
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_tile(tile: BasicTile) -> Placement {
+        SpawnPolicy::default().spawn(&Board::new(), tile)
+    }
+
+    #[test]
+    fn placements_on_empty_board() {
+        let board = Board::new();
+
+        // Placements are distinguished by angle, hence symmetric tiles count multiple times
+        assert_eq!(placements(&board, BasicTile::Square).count(), 4 * 5);
+        assert_eq!(placements(&board, BasicTile::Line).count(), 2 * 5 + 2 * 4);
+        assert_eq!(placements(&board, BasicTile::Diagonal).count(), 4 * 4);
+    }
+
+    #[test]
+    fn placements_are_valid_and_resting() {
+        let board = Board::new();
+
+        for tile in [BasicTile::Square, BasicTile::Line, BasicTile::Diagonal] {
+            for placement in placements(&board, tile) {
+                assert!(board.is_position_valid(&placement));
+                assert!(!board.is_position_valid(&placement.clone().displace_by(0, -1)));
+            }
+        }
+    }
+
+    #[test]
+    fn no_placements_if_spawn_blocked() {
+        let board = Board::new()
+            .freeze_tile_assume_valid(spawn_tile(BasicTile::Square))
+            .process_all_rows()
+            .0;
+
+        assert_eq!(placements(&board, BasicTile::Square).count(), 0);
+    }
+
+    #[test]
+    fn paths_reach_placements() {
+        let board = Board::new();
+
+        for tile in [BasicTile::Square, BasicTile::Line, BasicTile::Diagonal] {
+            let start = spawn_tile(tile.clone());
+
+            for target in placements(&board, tile) {
+                let path = find_path(&board, &start, &target).expect("Placement is reachable");
+
+                let mut state = TileState::from_placement(&start).unwrap();
+                for m in path {
+                    state = state.step(m).unwrap();
+                }
+                assert_eq!(Some(state), TileState::from_placement(&target));
+            }
+        }
+    }
+
+    #[test]
+    fn states_are_compact() {
+        assert_eq!(core::mem::size_of::<TileState>(), 1);
+        assert_eq!(core::mem::size_of::<Option<Move>>(), 1);
+
+        for idx in 0..NUM_STATES {
+            let state = TileState::from_idx(idx);
+            for m in Move::ALL {
+                if let Some(next) = state.step(m) {
+                    assert_eq!(next.step_back(m), state);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn path_is_shortest() {
+        let board = Board::new();
+        let start = spawn_tile(BasicTile::Square);
+        let target = start.clone().displace_by(-2, -3);
+
+        let path = find_path(&board, &start, &target).unwrap();
+
+        assert_eq!(path.len(), 5);
+    }
+
+    #[test]
+    fn no_path_to_other_tile_kind() {
+        let board = Board::new();
+        let start = spawn_tile(BasicTile::Square);
+        let target = spawn_tile(BasicTile::Line);
+
+        assert!(find_path(&board, &start, &target).is_none());
+    }
+
+    #[test]
+    fn no_path_into_covered_well() {
+        // Build a well at column 2 (0-indexed) and cover it
+        let mut board = Board::new();
+        for (x, y) in [(2, 1), (4, 1), (3, 2)] {
+            board = board
+                .freeze_tile_assume_valid(
+                    DisplacedTile::new(RotatedTile::new(BasicTile::Square)).displace_by(x, y),
+                )
+                .process_all_rows()
+                .0;
+        }
+        let start = spawn_tile(BasicTile::Square);
+        let target = DisplacedTile::new(RotatedTile::new(BasicTile::Square)).displace_by(3, 1);
+
+        assert!(board.is_position_valid(&target));
+        assert!(find_path(&board, &start, &target).is_none());
+    }
+}
@@ -0,0 +1,552 @@
+use crate::{
+    gameplay::{
+        board::{Board, BoardError, TakesTile},
+        game::{Game, GameError, Observer, Over, ProcessRows, TileFloating, TileNeeded},
+        raster::{Passive, RasterizationExt},
+    },
+    geometry::tile::BasicTile,
+};
+use either::Either;
+
+/// Maximum number of tiles in a puzzle's tile sequence.
+pub const MAX_PUZZLE_TILES: usize = 32;
+
+#[derive(Debug)]
+pub enum PuzzleError {
+    /// The starting board is not a valid board, see [`Board::from_grid`]
+    InvalidBoard(BoardError),
+    /// The tile sequence exceeds [`MAX_PUZZLE_TILES`]
+    TooManyTiles,
+    /// The goal cannot be met with the given tile sequence
+    UnreachableGoal,
+}
+
+/// Condition a puzzle is solved by.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Goal {
+    /// Clear at least the given number of rows
+    ClearRows(usize),
+    /// Clear all cells from the board
+    EmptyBoard,
+    /// Place the given number of tiles without the game being over
+    Survive(usize),
+}
+
+/// Outcome of an [`Attempt`] so far.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The goal has neither been met nor failed yet
+    Pending,
+    /// The goal has been met
+    Solved,
+    /// The goal cannot be met anymore
+    Failed,
+}
+
+/// Final tally of an [`Attempt`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Summary {
+    pub status: Status,
+    pub moves: usize,
+    pub par: Option<usize>,
+    pub cleared_rows: usize,
+    pub tiles: usize,
+}
+
+impl Summary {
+    /// Whether the puzzle has been solved in no more moves than its par, if it has any.
+    #[must_use]
+    pub fn is_within_par(&self) -> bool {
+        self.status == Status::Solved && self.par.is_none_or(|par| self.moves <= par)
+    }
+}
+
+/// Starting board, fixed tile sequence, goal and optional par.
+#[derive(Clone, Debug)]
+pub struct Puzzle {
+    board: Board<TakesTile>,
+    tiles: heapless::Vec<BasicTile, MAX_PUZZLE_TILES>,
+    goal: Goal,
+    par: Option<usize>,
+}
+
+impl Puzzle {
+    /// # Errors
+    ///
+    /// Returns [`PuzzleError::TooManyTiles`] iff `tiles` exceeds [`MAX_PUZZLE_TILES`] and
+    /// [`PuzzleError::UnreachableGoal`] iff `goal` requires more tiles than given.
+    pub fn new(
+        board: Board<TakesTile>,
+        tiles: &[BasicTile],
+        goal: Goal,
+    ) -> Result<Self, PuzzleError> {
+        let tiles = heapless::Vec::from_slice(tiles).map_err(|()| PuzzleError::TooManyTiles)?;
+
+        let reachable = match goal {
+            Goal::ClearRows(_) | Goal::EmptyBoard => !tiles.is_empty(),
+            Goal::Survive(num_tiles) => num_tiles <= tiles.len(),
+        };
+        if !reachable {
+            return Err(PuzzleError::UnreachableGoal);
+        }
+
+        Ok(Self {
+            board,
+            tiles,
+            goal,
+            par: None,
+        })
+    }
+
+    /// Expect the puzzle to be solvable in `par` moves, see [`Attempt::moves`].
+    #[must_use]
+    pub fn with_par(self, par: usize) -> Self {
+        Self {
+            par: Some(par),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn tiles(&self) -> &[BasicTile] {
+        &self.tiles
+    }
+
+    #[must_use]
+    pub fn goal(&self) -> &Goal {
+        &self.goal
+    }
+
+    #[must_use]
+    pub fn par(&self) -> Option<usize> {
+        self.par
+    }
+
+    /// Start a fresh game on the puzzle's board.
+    #[must_use]
+    pub fn start<O>(&self) -> PuzzleGame<'_, TileNeeded, O> {
+        let game = Game::with_board(self.board.clone());
+        let attempt = Attempt {
+            puzzle: self,
+            placed: 0,
+            cleared_rows: 0,
+            moves: 0,
+            status: Status::Pending,
+        };
+        PuzzleGame { game, attempt }
+    }
+}
+
+/// Progress of playing a [`Puzzle`], as tracked by a [`PuzzleGame`].
+#[derive(Clone, Debug)]
+pub struct Attempt<'a> {
+    puzzle: &'a Puzzle,
+    placed: usize,
+    cleared_rows: usize,
+    moves: usize,
+    status: Status,
+}
+
+impl Attempt<'_> {
+    /// Next tile of the puzzle's tile sequence, `None` if the sequence is exhausted or the
+    /// attempt is not pending anymore.
+    fn next_tile(&mut self) -> Option<BasicTile> {
+        if self.status != Status::Pending {
+            return None;
+        }
+
+        let tile = self.puzzle.tiles.get(self.placed).cloned();
+        if tile.is_some() {
+            self.placed += 1;
+        }
+        tile
+    }
+
+    /// Evaluate the goal against `game`, which has just finished processing rows after
+    /// dropping the tile last taken from [`Attempt::next_tile`].
+    ///
+    /// Once the attempt is solved or failed, its status does not change anymore.
+    fn evaluate<O>(&mut self, game: &Game<TileNeeded, O>) -> Status {
+        if self.status != Status::Pending {
+            return self.status;
+        }
+
+        self.cleared_rows += game.last_clear().count();
+        let raster = <Game<TileNeeded, O> as RasterizationExt<Passive>>::rasterize(game);
+
+        let solved = match self.puzzle.goal {
+            Goal::ClearRows(num_rows) => self.cleared_rows >= num_rows,
+            Goal::EmptyBoard => raster.is_empty(),
+            Goal::Survive(num_tiles) => self.placed >= num_tiles,
+        };
+
+        self.status = if solved {
+            Status::Solved
+        } else if self.placed >= self.puzzle.tiles.len() {
+            Status::Failed
+        } else {
+            Status::Pending
+        };
+        self.status
+    }
+
+    /// Report the game to be over, failing the attempt unless it has been solved already.
+    fn game_over(&mut self) -> Status {
+        if self.status == Status::Pending {
+            self.status = Status::Failed;
+        }
+        self.status
+    }
+
+    #[must_use]
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Number of rows cleared so far
+    #[must_use]
+    pub fn cleared_rows(&self) -> usize {
+        self.cleared_rows
+    }
+
+    /// Number of tiles taken from the tile sequence so far
+    #[must_use]
+    pub fn placed_tiles(&self) -> usize {
+        self.placed
+    }
+
+    /// Number of moves taken so far.
+    ///
+    /// Every successful [`Move`](crate::gameplay::placement::Move) of a floating tile counts,
+    /// i.e. moving it left or right, rotating it and descending it without freezing it, so the
+    /// moves of a tile add up the same way as the length of a
+    /// [`Path`](crate::gameplay::placement::Path) does.
+    #[must_use]
+    pub fn moves(&self) -> usize {
+        self.moves
+    }
+
+    #[must_use]
+    pub fn summary(&self) -> Summary {
+        Summary {
+            status: self.status,
+            moves: self.moves,
+            par: self.puzzle.par,
+            cleared_rows: self.cleared_rows,
+            tiles: self.placed,
+        }
+    }
+
+    fn count_move(&mut self, moved: Result<(), GameError>) -> Result<(), GameError> {
+        if moved.is_ok() {
+            self.moves += 1;
+        }
+        moved
+    }
+}
+
+/// [`Game`] played on a [`Puzzle`], taking tiles from the puzzle's tile sequence and tracking
+/// the puzzle's goal in an [`Attempt`].
+///
+/// The goal is evaluated exactly once per dropped tile, namely when the game transitions from
+/// [`ProcessRows`] to [`TileNeeded`], and the attempt fails as soon as the game is over.
+#[derive(Debug)]
+pub struct PuzzleGame<'a, S, O> {
+    game: Game<S, O>,
+    attempt: Attempt<'a>,
+}
+
+impl<'a, S, O> PuzzleGame<'a, S, O> {
+    #[must_use]
+    pub fn game(&self) -> &Game<S, O> {
+        &self.game
+    }
+
+    #[must_use]
+    pub fn attempt(&self) -> &Attempt<'a> {
+        &self.attempt
+    }
+
+    #[must_use]
+    pub fn status(&self) -> Status {
+        self.attempt.status
+    }
+
+    /// See [`Game::set_observer`].
+    pub fn set_observer(&mut self, observer: O) -> Result<(), GameError> {
+        self.game.set_observer(observer)
+    }
+
+    /// See [`Game::clear_observer`].
+    pub fn clear_observer(&mut self) -> Result<O, GameError> {
+        self.game.clear_observer()
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> (Game<S, O>, Attempt<'a>) {
+        (self.game, self.attempt)
+    }
+
+    fn with_game<T>(game: Game<T, O>, attempt: Attempt<'a>) -> PuzzleGame<'a, T, O> {
+        PuzzleGame { game, attempt }
+    }
+}
+
+/// Outcome of [`PuzzleGame::place_tile`].
+#[derive(Debug)]
+pub enum Placed<'a, O> {
+    /// The tile has been spawned
+    Floating(PuzzleGame<'a, TileFloating, O>),
+    /// Spawning the tile ended the game, failing the attempt unless it has been solved already
+    Over(PuzzleGame<'a, Over, O>),
+    /// There is no tile left to place, the game is returned unchanged
+    Exhausted(PuzzleGame<'a, TileNeeded, O>),
+}
+
+impl<'a, O> PuzzleGame<'a, TileNeeded, O>
+where
+    O: Observer,
+{
+    /// Spawn the next tile of the puzzle's tile sequence, see [`Game::place_tile`].
+    ///
+    /// There is no tile left to place once the tile sequence is exhausted or the attempt is not
+    /// pending anymore.
+    #[must_use]
+    pub fn place_tile(mut self) -> Placed<'a, O> {
+        let Some(tile) = self.attempt.next_tile() else {
+            return Placed::Exhausted(self);
+        };
+
+        match self.game.place_tile(tile) {
+            Either::Left(game) => Placed::Floating(Self::with_game(game, self.attempt)),
+            Either::Right(game) => {
+                self.attempt.game_over();
+                Placed::Over(Self::with_game(game, self.attempt))
+            }
+        }
+    }
+}
+
+impl<'a, O> PuzzleGame<'a, TileFloating, O>
+where
+    O: Observer,
+{
+    /// See [`Game::move_tile_left`].
+    pub fn move_tile_left(&mut self) -> Result<(), GameError> {
+        let moved = self.game.move_tile_left();
+        self.attempt.count_move(moved)
+    }
+
+    /// See [`Game::move_tile_right`].
+    pub fn move_tile_right(&mut self) -> Result<(), GameError> {
+        let moved = self.game.move_tile_right();
+        self.attempt.count_move(moved)
+    }
+
+    /// See [`Game::rotate_tile`].
+    pub fn rotate_tile(&mut self) -> Result<(), GameError> {
+        let moved = self.game.rotate_tile();
+        self.attempt.count_move(moved)
+    }
+
+    /// See [`Game::descend_tile`].
+    #[must_use]
+    pub fn descend_tile(mut self) -> Either<Self, PuzzleGame<'a, ProcessRows, O>> {
+        match self.game.descend_tile() {
+            Either::Left(game) => {
+                self.attempt.moves += 1;
+                Either::Left(Self::with_game(game, self.attempt))
+            }
+            Either::Right(game) => Either::Right(Self::with_game(game, self.attempt)),
+        }
+    }
+}
+
+impl<'a, O> PuzzleGame<'a, ProcessRows, O>
+where
+    O: Observer,
+{
+    /// See [`Game::process_row`], evaluating the goal once all rows have been processed.
+    #[must_use]
+    pub fn process_row(mut self) -> Either<Self, PuzzleGame<'a, TileNeeded, O>> {
+        match self.game.process_row() {
+            Either::Left(game) => Either::Left(Self::with_game(game, self.attempt)),
+            Either::Right(game) => {
+                self.attempt.evaluate(&game);
+                Either::Right(Self::with_game(game, self.attempt))
+            }
+        }
+    }
+
+    /// See [`Game::process_all_rows`], evaluating the goal afterwards.
+    #[must_use]
+    pub fn process_all_rows(mut self) -> PuzzleGame<'a, TileNeeded, O> {
+        let (game, _) = self.game.process_all_rows();
+        self.attempt.evaluate(&game);
+        Self::with_game(game, self.attempt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        gameplay::{board::BOARD_COLS, game::NoopObserver},
+        geometry::grid::Grid,
+    };
+    use either::Either;
+
+    type Pending<'a> = PuzzleGame<'a, TileNeeded, NoopObserver>;
+
+    /// Move the next tile as far right as possible (if requested) and drop it.
+    fn drop_tile(game: Pending<'_>, right: bool) -> Pending<'_> {
+        let Placed::Floating(mut game) = game.place_tile() else {
+            panic!("Game should not be over")
+        };
+        while right && game.move_tile_right().is_ok() {}
+
+        loop {
+            game = match game.descend_tile() {
+                Either::Left(game) => game,
+                Either::Right(game) => return game.process_all_rows(),
+            }
+        }
+    }
+
+    fn almost_full_row() -> Board<TakesTile> {
+        Board::from_grid(Grid::from([
+            [true, true, true, true, false],
+            [false; BOARD_COLS],
+            [false; BOARD_COLS],
+            [false; BOARD_COLS],
+            [false; BOARD_COLS],
+        ]))
+        .unwrap()
+    }
+
+    fn play(puzzle: &Puzzle, right: bool) -> Status {
+        let mut game = puzzle.start::<NoopObserver>();
+
+        while game.status() == Status::Pending {
+            game = drop_tile(game, right);
+        }
+        game.status()
+    }
+
+    #[test]
+    fn clear_rows() {
+        let puzzle =
+            Puzzle::new(almost_full_row(), &[BasicTile::Square], Goal::ClearRows(1)).unwrap();
+
+        assert_eq!(play(&puzzle, true), Status::Solved);
+        assert_eq!(play(&puzzle, false), Status::Failed);
+    }
+
+    #[test]
+    fn rows_are_counted_once() {
+        let puzzle = Puzzle::new(
+            almost_full_row(),
+            &[BasicTile::Square, BasicTile::Line],
+            Goal::ClearRows(2),
+        )
+        .unwrap();
+
+        // Processing row by row evaluates the goal on the last step only
+        let Placed::Floating(mut game) = puzzle.start::<NoopObserver>().place_tile() else {
+            panic!("Game should not be over")
+        };
+        while game.move_tile_right().is_ok() {}
+        let mut game = loop {
+            game = match game.descend_tile() {
+                Either::Left(game) => game,
+                Either::Right(game) => break game,
+            }
+        };
+        let game = loop {
+            game = match game.process_row() {
+                Either::Left(game) => game,
+                Either::Right(game) => break game,
+            }
+        };
+
+        assert_eq!(game.attempt().cleared_rows(), 1);
+        assert_eq!(game.status(), Status::Pending);
+    }
+
+    #[test]
+    fn empty_board() {
+        let puzzle =
+            Puzzle::new(almost_full_row(), &[BasicTile::Square], Goal::EmptyBoard).unwrap();
+
+        assert_eq!(play(&puzzle, true), Status::Solved);
+        assert_eq!(play(&puzzle, false), Status::Failed);
+    }
+
+    #[test]
+    fn survive() {
+        let puzzle = Puzzle::new(
+            Board::new(),
+            &[BasicTile::Line, BasicTile::Line, BasicTile::Line],
+            Goal::Survive(2),
+        )
+        .unwrap();
+        let game = puzzle.start::<NoopObserver>();
+
+        let game = drop_tile(game, false);
+        assert_eq!(game.status(), Status::Pending);
+        let game = drop_tile(game, false);
+        assert_eq!(game.status(), Status::Solved);
+        assert!(matches!(game.place_tile(), Placed::Exhausted(_)));
+    }
+
+    #[test]
+    fn game_over_fails() {
+        let tiles = [
+            BasicTile::Line,
+            BasicTile::Line,
+            BasicTile::Line,
+            BasicTile::Line,
+        ];
+        let puzzle = Puzzle::new(Board::new(), &tiles, Goal::Survive(4)).unwrap();
+        let mut game = puzzle.start::<NoopObserver>();
+
+        // Stacking vertical lines in the same column ends the game on the third tile
+        for _ in 0..2 {
+            game = drop_tile(game, false);
+            assert_eq!(game.status(), Status::Pending);
+        }
+        let Placed::Over(game) = game.place_tile() else {
+            panic!("Game should be over")
+        };
+        assert_eq!(game.status(), Status::Failed);
+        assert_eq!(game.attempt().placed_tiles(), 3);
+    }
+
+    #[test]
+    fn par_moves() {
+        let puzzle = Puzzle::new(almost_full_row(), &[BasicTile::Square], Goal::ClearRows(1))
+            .unwrap()
+            .with_par(6);
+
+        // The square spawns at column 2 (0-indexed) and row 4, so it takes two moves right and
+        // four descends to fill the gap
+        let game = drop_tile(puzzle.start::<NoopObserver>(), true);
+        let summary = game.attempt().summary();
+        assert_eq!(summary.moves, 6);
+        assert_eq!(summary.par, Some(6));
+        assert!(summary.is_within_par());
+
+        let tight = puzzle.clone().with_par(5);
+        let game = drop_tile(tight.start::<NoopObserver>(), true);
+        assert_eq!(game.status(), Status::Solved);
+        assert!(!game.attempt().summary().is_within_par());
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        assert!(matches!(
+            Puzzle::new(Board::new(), &[BasicTile::Line], Goal::Survive(2)),
+            Err(PuzzleError::UnreachableGoal)
+        ));
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{
+    gameplay::{
+        board::{Board, TakesTile, BOARD_COLS, BOARD_ROWS},
+        placement::{Placement, OFFSETS},
+    },
+    geometry::tile::{
+        Angle, BasicTile, Discrete2DSet, DisplacedTile, Displacee, RotatedTile, Rotatee,
+    },
+};
+
+/// Seed used by [`Column::Random`] unless specified otherwise, see [`SpawnPolicy::with_seed`].
+pub const DEFAULT_SEED: u32 = 0x2545_f491;
+
+/// Column a [`SpawnPolicy`] spawns tiles at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Column {
+    /// The board's center column
+    Center,
+    /// The given 0-indexed column, counted from the left, see
+    /// [`Game::tile_column`](crate::gameplay::game::Game::tile_column)
+    Fixed(u8),
+    /// A pseudo-random column out of all columns the tile fits into
+    Random,
+    /// The leftmost column the tile can be spawned at without overlapping the board's cells
+    FirstFree,
+}
+
+/// Rule deciding where [`Game::place_tile`](crate::gameplay::game::Game::place_tile) spawns a
+/// fresh tile.
+///
+/// Tiles are always spawned as high as possible, i.e. with their topmost cell in the board's top
+/// row. Horizontally, the tile's origin is put at the policy's [`Column`], but never so far out
+/// that any of the tile's cells would leave the board.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpawnPolicy {
+    column: Column,
+    angle: Angle,
+    state: u32,
+}
+
+impl SpawnPolicy {
+    /// Spawn tiles at `column` and [`Angle::Zero`].
+    #[must_use]
+    pub fn new(column: Column) -> Self {
+        Self {
+            column,
+            angle: Angle::Zero,
+            state: DEFAULT_SEED,
+        }
+    }
+
+    /// Spawn tiles at `angle` instead.
+    #[must_use]
+    pub fn with_angle(self, angle: Angle) -> Self {
+        Self { angle, ..self }
+    }
+
+    /// Seed the pseudo-random sequence of [`Column::Random`].
+    ///
+    /// As the sequence is generated by a xorshift generator, a seed of `0` is replaced by
+    /// [`DEFAULT_SEED`].
+    #[must_use]
+    pub fn with_seed(self, seed: u32) -> Self {
+        let state = if seed == 0 { DEFAULT_SEED } else { seed };
+        Self { state, ..self }
+    }
+
+    #[must_use]
+    pub fn column(&self) -> &Column {
+        &self.column
+    }
+
+    #[must_use]
+    pub fn angle(&self) -> &Angle {
+        &self.angle
+    }
+
+    /// Position to spawn `tile` at on `board`.
+    ///
+    /// The returned position is not guaranteed to be valid on `board`, in which case the game is
+    /// over.
+    pub fn spawn(&mut self, board: &Board<TakesTile>, tile: BasicTile) -> Placement {
+        let mut rotated = RotatedTile::new(tile);
+        while rotated.angle() != &self.angle {
+            rotated = rotated.rotate_ccw();
+        }
+
+        let (min_x, max_x, max_y) = extent(&rotated);
+        // range of displacements keeping all of the tile's cells on the board, the board's
+        // leftmost column being at displacement 1
+        let lowest = 1 - min_x;
+        let highest = i32::try_from(BOARD_COLS).expect("Hardcoded range should be valid") - max_x;
+        let displ_y = i32::try_from(BOARD_ROWS).expect("Hardcoded range should be valid") - max_y;
+
+        let at = |displ_x: i32| DisplacedTile::new(rotated.clone()).displace_by(displ_x, displ_y);
+
+        let displ_x = match self.column {
+            Column::Center => {
+                i32::try_from((BOARD_COLS >> 1) + 1).expect("Hardcoded range should be valid")
+            }
+            Column::Fixed(col) => i32::from(col) + 1,
+            Column::Random => {
+                let num_cols = u32::try_from(highest - lowest + 1)
+                    .expect("Every tile fits onto the board horizontally");
+                let offset = self.next_random() % num_cols;
+                lowest + i32::try_from(offset).expect("Hardcoded range should be valid")
+            }
+            Column::FirstFree => (lowest..=highest)
+                .find(|displ_x| board.is_position_valid(&at(*displ_x)))
+                .unwrap_or(lowest),
+        };
+
+        at(displ_x.clamp(lowest, highest))
+    }
+
+    /// Advance the xorshift generator.
+    fn next_random(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+}
+
+impl Default for SpawnPolicy {
+    /// Spawn tiles at [`Column::Center`] and [`Angle::Zero`].
+    fn default() -> Self {
+        Self::new(Column::Center)
+    }
+}
+
+/// Leftmost and rightmost horizontal and topmost vertical offset of `tile`'s cells relative to the
+/// tile's origin.
+fn extent(tile: &RotatedTile<BasicTile>) -> (i32, i32, i32) {
+    let cells = || {
+        OFFSETS
+            .iter()
+            .flat_map(|x| OFFSETS.iter().map(move |y| (*x, *y)))
+            .filter(|(x, y)| tile.contains(*x, *y))
+    };
+
+    let min_x = cells().map(|(x, _)| x).min().unwrap_or(0);
+    let max_x = cells().map(|(x, _)| x).max().unwrap_or(0);
+    let max_y = cells().map(|(_, y)| y).max().unwrap_or(0);
+    (min_x, max_x, max_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn displacement(placement: &Placement) -> (i32, i32) {
+        (*placement.displ_x(), *placement.displ_y())
+    }
+
+    #[test]
+    fn default_spawns_at_center_top() {
+        let board = Board::new();
+        let mut policy = SpawnPolicy::default();
+
+        assert_eq!(
+            displacement(&policy.spawn(&board, BasicTile::Square)),
+            (3, 5)
+        );
+        assert_eq!(displacement(&policy.spawn(&board, BasicTile::Line)), (3, 4));
+        assert_eq!(
+            displacement(&policy.spawn(&board, BasicTile::Diagonal)),
+            (3, 4)
+        );
+    }
+
+    #[test]
+    fn rotated_spawn_stays_on_board() {
+        let board = Board::new();
+        let mut policy = SpawnPolicy::new(Column::Fixed(0)).with_angle(Angle::Ninety);
+
+        // A horizontal line extends to the left of its origin
+        let line = policy.spawn(&board, BasicTile::Line);
+        assert_eq!(displacement(&line), (2, 5));
+        assert!(board.is_position_valid(&line));
+
+        let mut policy = SpawnPolicy::new(Column::Fixed(4)).with_angle(Angle::OneEighty);
+        let diagonal = policy.spawn(&board, BasicTile::Diagonal);
+        assert!(board.is_position_valid(&diagonal));
+        assert_eq!(displacement(&diagonal), (5, 5));
+    }
+
+    #[test]
+    fn random_columns_are_valid() {
+        let board = Board::new();
+        let mut policy = SpawnPolicy::new(Column::Random).with_seed(7);
+        let mut seen = [false; BOARD_COLS];
+
+        for _ in 0..64 {
+            let placement = policy.spawn(&board, BasicTile::Square);
+            assert!(board.is_position_valid(&placement));
+            seen[usize::try_from(*placement.displ_x() - 1).unwrap()] = true;
+        }
+        assert_eq!(seen, [true; BOARD_COLS]);
+    }
+
+    #[test]
+    fn first_free_column() {
+        let mut board = Board::new();
+        for x in 1..=2 {
+            board = board
+                .freeze_tile_assume_valid(
+                    DisplacedTile::new(RotatedTile::new(BasicTile::Line)).displace_by(x, 4),
+                )
+                .process_all_rows()
+                .0;
+        }
+        let mut policy = SpawnPolicy::new(Column::FirstFree);
+
+        assert_eq!(displacement(&policy.spawn(&board, BasicTile::Line)), (3, 4));
+    }
+}
@@ -1,14 +1,26 @@
-use core::fmt::{Display, Write};
+use core::{
+    fmt::{Display, Write},
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign},
+    str::FromStr,
+};
 
 use super::tile::{BasicTile, Discrete2DSet, DisplacedTile, RotatedTile};
 use paste::paste;
 
+mod mask;
+
+pub(crate) use mask::{TileMask, Token};
+
 #[derive(Debug)]
 pub enum GridError {
     /// Access using invalid index `(row, col)`
     InvalidIndex(Option<usize>, Option<usize>),
     /// Non-empty set lead to an empty grid representation
     EmptyIntersection,
+    /// Malformed drawing at 1-indexed `(line, column)`
+    InvalidDrawing(usize, usize),
+    /// Raw bits with the given bits set outside of the grid
+    InvalidBits(u64),
 }
 
 /// 5 by 5 grid encoded in an `u32`
@@ -53,7 +65,7 @@ impl Grid {
         0x0,
     ];
 
-    fn new(grid: u32) -> Self {
+    const fn new(grid: u32) -> Self {
         Self(grid)
     }
 
@@ -75,6 +87,20 @@ impl Grid {
         }
     }
 
+    /// Grid encoded in `bits`, see [`to_bits`](Self::to_bits)
+    pub fn from_bits(bits: u32) -> Result<Self, GridError> {
+        match bits & !Self::ALL {
+            0 => Ok(Self::new(bits)),
+            invalid => Err(GridError::InvalidBits(invalid.into())),
+        }
+    }
+
+    /// Raw encoding, element `(row, col)` being stored in bit `row * NUM_COLS + col`
+    #[must_use]
+    pub fn to_bits(&self) -> u32 {
+        self.0
+    }
+
     #[must_use]
     pub fn overlaps(&self, other: &Self) -> bool {
         (self.0 & other.0) != 0
@@ -120,6 +146,328 @@ impl Grid {
             .ok_or(GridError::InvalidIndex(Some(row), Some(col)))
     }
 
+    const ROW_MASK: u32 = Self::R0_RAW;
+
+    /// Number of set elements in each row, bottom row first
+    #[must_use]
+    pub fn row_fill_counts(&self) -> [usize; Self::NUM_ROWS] {
+        array_init::array_init(|row| {
+            ((self.0 >> (row * Self::NUM_COLS)) & Self::ROW_MASK).count_ones() as usize
+        })
+    }
+
+    /// Number of fully populated rows
+    #[must_use]
+    pub fn full_rows(&self) -> usize {
+        self.full_row_mask().count_ones() as usize
+    }
+
+    /// Elements of the leftmost column
+    const COL_MASK: u32 = 0x0010_8421;
+
+    /// Fully populated rows as a mask, bit `row` being set iff row `row` is fully populated.
+    #[must_use]
+    pub fn full_row_mask(&self) -> u8 {
+        // After and-ing all columns onto the leftmost column, the element at `(row, 0)` is set
+        // iff `row` is fully populated
+        let full =
+            self.0 & (self.0 >> 1) & (self.0 >> 2) & (self.0 >> 3) & (self.0 >> 4) & Self::COL_MASK;
+
+        // Gather bit `row * NUM_COLS` into bit `row`. No other bit lands in the lowest
+        // `NUM_ROWS` bits, since `row * NUM_COLS - other * (NUM_COLS - 1)` is outside the range
+        // `0..NUM_ROWS` for `row != other`.
+        let gathered = full | (full >> 4) | (full >> 8) | (full >> 12) | (full >> 16);
+        (gathered & 0x1f)
+            .try_into()
+            .expect("Hardcoded range should be valid")
+    }
+
+    /// Grid with exactly the rows given by `mask` populated, see [`Grid::full_row_mask`].
+    pub fn from_row_mask(mask: u8) -> Result<Self, GridError> {
+        Self::check_row_mask(mask)?;
+
+        Ok(Self::new(
+            (0..Self::NUM_ROWS)
+                .filter(|row| mask & (1 << row) != 0)
+                .fold(0, |grid, row| grid | Self::ROWS[row].0),
+        ))
+    }
+
+    /// Discard the rows given by `mask`, see [`Grid::full_row_mask`], moving the remaining rows
+    /// downwards to close the gaps.
+    pub fn discard_rows(self, mask: u8) -> Result<Self, GridError> {
+        Self::check_row_mask(mask)?;
+
+        let mut grid = 0;
+        let mut kept = 0;
+        for row in 0..Self::NUM_ROWS {
+            if mask & (1 << row) == 0 {
+                let elements = (self.0 >> (row * Self::NUM_COLS)) & Self::ROW_MASK;
+                grid |= elements << (kept * Self::NUM_COLS);
+                kept += 1;
+            }
+        }
+
+        Ok(Self::new(grid))
+    }
+
+    fn check_row_mask(mask: u8) -> Result<(), GridError> {
+        if mask >> Self::NUM_ROWS == 0 {
+            Ok(())
+        } else {
+            let highest = u8::BITS - 1 - mask.leading_zeros();
+            Err(GridError::InvalidIndex(Some(highest as usize), None))
+        }
+    }
+
+    const ALL: u32 = Self::ROWS_BELOW[4] | Self::R4_RAW;
+
+    /// Elements of the columns `0..num_cols`
+    const fn columns_below(num_cols: usize) -> u32 {
+        let mut mask = 0;
+        let mut col = 0;
+        while col < num_cols && col < Self::NUM_COLS {
+            mask |= Self::COL_MASK << col;
+            col += 1;
+        }
+        mask
+    }
+
+    /// Elements of the rows `0..num_rows`
+    const fn rows_below(num_rows: usize) -> u32 {
+        if num_rows >= Self::NUM_ROWS {
+            Self::ALL
+        } else {
+            Self::ROWS_BELOW[num_rows]
+        }
+    }
+
+    const fn fill(mask: u32, fill: bool) -> u32 {
+        if fill {
+            mask
+        } else {
+            0
+        }
+    }
+
+    /// Move all elements `n` columns to the left, setting the vacated columns iff `fill`.
+    pub fn shift_left(self, n: usize, fill: bool) -> Self {
+        let n = n.min(Self::NUM_COLS);
+        let kept = Self::columns_below(Self::NUM_COLS - n);
+        Self::new(((self.0 >> n) & kept) | Self::fill(Self::ALL & !kept, fill))
+    }
+
+    /// Move all elements `n` columns to the right, setting the vacated columns iff `fill`.
+    pub fn shift_right(self, n: usize, fill: bool) -> Self {
+        let n = n.min(Self::NUM_COLS);
+        let vacated = Self::columns_below(n);
+        Self::new(((self.0 << n) & Self::ALL & !vacated) | Self::fill(vacated, fill))
+    }
+
+    /// Move all elements `n` rows up, setting the vacated rows iff `fill`.
+    pub fn shift_up(self, n: usize, fill: bool) -> Self {
+        let vacated = Self::rows_below(n);
+        let shifted = if n >= Self::NUM_ROWS {
+            0
+        } else {
+            (self.0 << (n * Self::NUM_COLS)) & Self::ALL
+        };
+        Self::new(shifted | Self::fill(vacated, fill))
+    }
+
+    /// Move all elements `n` rows down, setting the vacated rows iff `fill`.
+    pub fn shift_down(self, n: usize, fill: bool) -> Self {
+        let n = n.min(Self::NUM_ROWS);
+        let kept = Self::rows_below(Self::NUM_ROWS - n);
+        let shifted = if n >= Self::NUM_ROWS {
+            0
+        } else {
+            self.0 >> (n * Self::NUM_COLS)
+        };
+        Self::new(shifted | Self::fill(Self::ALL & !kept, fill))
+    }
+
+    /// Swap left and right.
+    pub fn mirror_horizontal(self) -> Self {
+        let mut grid = 0;
+        for col in 0..Self::NUM_COLS {
+            let column = (self.0 >> col) & Self::COL_MASK;
+            grid |= column << (Self::NUM_COLS - 1 - col);
+        }
+        Self::new(grid)
+    }
+
+    /// Swap top and bottom.
+    pub fn mirror_vertical(self) -> Self {
+        let mut grid = 0;
+        for row in 0..Self::NUM_ROWS {
+            let elements = (self.0 >> (row * Self::NUM_COLS)) & Self::ROW_MASK;
+            grid |= elements << ((Self::NUM_ROWS - 1 - row) * Self::NUM_COLS);
+        }
+        Self::new(grid)
+    }
+
+    /// Mirror along the diagonal from the bottom left to the top right element, i.e. move the
+    /// element at `(row, col)` to `(col, row)`.
+    pub fn transpose(self) -> Self {
+        let mut grid = 0;
+        for row in 0..Self::NUM_ROWS {
+            for col in 0..Self::NUM_COLS {
+                if self.0 & (1 << (row * Self::NUM_COLS + col)) != 0 {
+                    grid |= 1 << (col * Self::NUM_COLS + row);
+                }
+            }
+        }
+        Self::new(grid)
+    }
+
+    /// Rotate by 90 degrees in counter-clockwise order
+    pub fn rotate_ccw(self) -> Self {
+        self.transpose().mirror_horizontal()
+    }
+
+    /// Rotate by 90 degrees in clockwise order
+    pub fn rotate_cw(self) -> Self {
+        self.transpose().mirror_vertical()
+    }
+
+    /// Rotate by 180 degrees
+    pub fn rotate_180(self) -> Self {
+        self.mirror_horizontal().mirror_vertical()
+    }
+
+    /// Number of set elements
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Number of set elements in `row`
+    pub fn row_count(&self, row: usize) -> Result<usize, GridError> {
+        if row >= Self::NUM_ROWS {
+            return Err(GridError::InvalidIndex(Some(row), None));
+        }
+        Ok(self.intersect(&Self::ROWS[row]).count_ones())
+    }
+
+    /// Number of set elements in `col`
+    pub fn column_count(&self, col: usize) -> Result<usize, GridError> {
+        if col >= Self::NUM_COLS {
+            return Err(GridError::InvalidIndex(None, Some(col)));
+        }
+        Ok((self.0 & (Self::COL_MASK << col)).count_ones() as usize)
+    }
+
+    /// Set elements as `(row, col)`, bottom row first and left to right within each row
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> {
+        let mut bits = self.0;
+        core::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let idx = bits.trailing_zeros() as usize;
+            // clear the lowest set bit
+            bits &= bits - 1;
+            Some((idx / Self::NUM_COLS, idx % Self::NUM_COLS))
+        })
+    }
+
+    /// Rows as bit patterns, bottom row first, bit `col` being set iff element `(row, col)` is
+    /// set
+    pub fn rows(&self) -> impl Iterator<Item = u8> {
+        let bits = self.0;
+        (0..Self::NUM_ROWS).map(move |row| {
+            ((bits >> (row * Self::NUM_COLS)) & Self::ROW_MASK)
+                .try_into()
+                .expect("Hardcoded range should be valid")
+        })
+    }
+
+    /// Columns as bit patterns, left column first, bit `row` being set iff element `(row, col)`
+    /// is set
+    pub fn columns(&self) -> impl Iterator<Item = u8> {
+        let transposed = self.clone().transpose();
+        (0..Self::NUM_COLS).map(move |col| {
+            ((transposed.0 >> (col * Self::NUM_COLS)) & Self::ROW_MASK)
+                .try_into()
+                .expect("Hardcoded range should be valid")
+        })
+    }
+
+    /// Height of each column, left column first
+    ///
+    /// A column's height is the number of rows up to and including its topmost set element.
+    #[must_use]
+    pub fn column_heights(&self) -> [usize; Self::NUM_COLS] {
+        array_init::array_init(|col| {
+            (0..Self::NUM_ROWS)
+                .rev()
+                .find(|row| {
+                    self.is_element_set(*row, col)
+                        .expect("Hardcoded range should be valid")
+                })
+                .map_or(0, |row| row + 1)
+        })
+    }
+
+    /// Height of the highest column
+    #[must_use]
+    pub fn max_height(&self) -> usize {
+        self.column_heights().into_iter().max().unwrap_or(0)
+    }
+
+    /// Sum of all column heights
+    #[must_use]
+    pub fn aggregate_height(&self) -> usize {
+        self.column_heights().iter().sum()
+    }
+
+    /// Number of holes in each column, left column first
+    ///
+    /// A hole is an unset element below its column's topmost set element.
+    #[must_use]
+    pub fn column_holes(&self) -> [usize; Self::NUM_COLS] {
+        let heights = self.column_heights();
+        array_init::array_init(|col| {
+            (0..heights[col])
+                .filter(|row| {
+                    !self
+                        .is_element_set(*row, col)
+                        .expect("Hardcoded range should be valid")
+                })
+                .count()
+        })
+    }
+
+    /// Total number of holes, see [`Grid::column_holes`]
+    #[must_use]
+    pub fn holes(&self) -> usize {
+        self.column_holes().iter().sum()
+    }
+
+    /// Sum of absolute height differences between neighbouring columns
+    #[must_use]
+    pub fn bumpiness(&self) -> usize {
+        self.column_heights()
+            .windows(2)
+            .map(|pair| pair[0].abs_diff(pair[1]))
+            .sum()
+    }
+
+    /// Depth of the well at each column, left column first
+    ///
+    /// A column's well depth is the number of rows its height falls short of the lower of its
+    /// neighbouring columns. The grid's sides count as columns of full height.
+    #[must_use]
+    pub fn wells(&self) -> [usize; Self::NUM_COLS] {
+        let heights = self.column_heights();
+        array_init::array_init(|col| {
+            let left = col.checked_sub(1).map_or(Self::NUM_ROWS, |l| heights[l]);
+            let right = heights.get(col + 1).copied().unwrap_or(Self::NUM_ROWS);
+            left.min(right).saturating_sub(heights[col])
+        })
+    }
+
     /// Discard specified row and shift all rows above downwards by one row
     pub fn discard_and_shift(self, row: usize) -> Result<Self, GridError> {
         if row >= Self::NUM_ROWS {
@@ -139,28 +487,247 @@ impl Default for Grid {
     }
 }
 
+/// Draw the grid of size `num_rows` by `num_cols`, top row first.
+fn write_drawing<F>(
+    f: &mut core::fmt::Formatter<'_>,
+    num_rows: usize,
+    num_cols: usize,
+    is_element_set: F,
+) -> core::fmt::Result
+where
+    F: Fn(usize, usize) -> bool,
+{
+    let write_border = |f: &mut core::fmt::Formatter<'_>| -> core::fmt::Result {
+        f.write_char('+')?;
+        for _ in 0..num_cols {
+            f.write_char('-')?;
+        }
+        f.write_str("+\n")
+    };
+
+    write_border(f)?;
+
+    for row in (0..num_rows).rev() {
+        f.write_char('|')?;
+
+        for col in 0..num_cols {
+            if is_element_set(row, col) {
+                f.write_char('x')?;
+            } else {
+                f.write_char(' ')?;
+            }
+        }
+
+        f.write_str("|\n")?;
+    }
+
+    write_border(f)
+}
+
 impl Display for Grid {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("+-----+\n")?;
+        write_drawing(f, Self::NUM_ROWS, Self::NUM_COLS, |row, col| {
+            self.is_element_set(row, col)
+                .expect("Hardcoded range should be valid")
+        })
+    }
+}
 
-        for row in (0..Grid::NUM_ROWS).rev() {
-            f.write_char('|')?;
+const fn is_blank(c: u8) -> bool {
+    c == b' ' || c == b'\t' || c == b'\r'
+}
 
-            for col in 0..Grid::NUM_COLS {
-                if self
-                    .is_element_set(row, col)
-                    .expect("Hardcoded range should be valid")
-                {
-                    f.write_char('x')?;
-                } else {
-                    f.write_char(' ')?;
-                }
+/// Check `s[start..stop]` to be a horizontal border like `+-----+` of width `num_cols`.
+///
+/// On failure, returns the index of the offending byte.
+pub(crate) const fn parse_border(
+    s: &[u8],
+    start: usize,
+    stop: usize,
+    num_cols: usize,
+) -> Result<(), usize> {
+    let mut idx = start;
+    while idx < start + num_cols + 2 {
+        let expected = if idx == start || idx == start + num_cols + 1 {
+            b'+'
+        } else {
+            b'-'
+        };
+        if idx >= stop || s[idx] != expected {
+            return Err(idx);
+        }
+        idx += 1;
+    }
+
+    if stop > idx {
+        return Err(idx);
+    }
+    Ok(())
+}
+
+/// Parse `s[start..stop]` as a drawn row like `|x  x |` of width `num_cols`.
+///
+/// On success, bit `col` of the returned value is set iff element `col` is set.
+/// On failure, returns the index of the offending byte.
+pub(crate) const fn parse_row(
+    s: &[u8],
+    start: usize,
+    stop: usize,
+    num_cols: usize,
+) -> Result<u64, usize> {
+    let mut bits = 0;
+    let mut idx = start;
+    while idx < start + num_cols + 2 {
+        if idx >= stop {
+            return Err(idx);
+        }
+        if idx == start || idx == start + num_cols + 1 {
+            if s[idx] != b'|' {
+                return Err(idx);
+            }
+        } else {
+            match s[idx] {
+                b'x' => bits |= 1 << (idx - start - 1),
+                b' ' => {}
+                _ => return Err(idx),
             }
+        }
+        idx += 1;
+    }
+
+    if stop > idx {
+        return Err(idx);
+    }
+    Ok(bits)
+}
 
-            f.write_str("|\n")?;
+/// Parse a drawing of size `num_rows` by `num_cols` as produced by `Display`.
+///
+/// Indentation, trailing whitespace and empty lines around the drawing are ignored.
+/// On success, the elements are returned in row major order, starting at the bottom row.
+/// On failure, the 1-indexed `(line, column)` of the offending character is returned.
+const fn parse_drawing(s: &[u8], num_rows: usize, num_cols: usize) -> Result<u64, (usize, usize)> {
+    let mut bits = 0;
+    let mut line = 0;
+    let mut pos = 0;
+    // Number of lines of the drawing (including borders) parsed so far
+    let mut drawn = 0;
+
+    while pos < s.len() {
+        let mut end = pos;
+        while end < s.len() && s[end] != b'\n' {
+            end += 1;
         }
-        f.write_str("+-----+\n")?;
-        Ok(())
+
+        let mut start = pos;
+        while start < end && is_blank(s[start]) {
+            start += 1;
+        }
+        let mut stop = end;
+        while stop > start && is_blank(s[stop - 1]) {
+            stop -= 1;
+        }
+        line += 1;
+
+        if start == stop {
+            // Empty lines within the drawing are not allowed
+            if drawn != 0 && drawn != num_rows + 2 {
+                return Err((line, start - pos + 1));
+            }
+        } else if drawn == 0 || drawn == num_rows + 1 {
+            if let Err(idx) = parse_border(s, start, stop, num_cols) {
+                return Err((line, idx - pos + 1));
+            }
+            drawn += 1;
+        } else if drawn <= num_rows {
+            // Rows are drawn top to bottom
+            match parse_row(s, start, stop, num_cols) {
+                Ok(row_bits) => bits |= row_bits << ((num_rows - drawn) * num_cols),
+                Err(idx) => return Err((line, idx - pos + 1)),
+            }
+            drawn += 1;
+        } else {
+            return Err((line, start - pos + 1));
+        }
+
+        pos = end + 1;
+    }
+
+    if drawn != num_rows + 2 {
+        return Err((line + 1, 1));
+    }
+    Ok(bits)
+}
+
+impl Grid {
+    /// Parse a drawing as produced by `Display`, see also [`grid!`](crate::grid).
+    ///
+    /// Indentation, trailing whitespace and empty lines around the drawing are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GridError::InvalidDrawing`] locating the first malformed character.
+    pub const fn from_drawing(s: &str) -> Result<Self, GridError> {
+        match parse_drawing(s.as_bytes(), Self::NUM_ROWS, Self::NUM_COLS) {
+            // 25 bits at most, so the cast is lossless
+            #[allow(clippy::cast_possible_truncation)]
+            Ok(bits) => Ok(Self::new(bits as u32)),
+            Err((line, col)) => Err(GridError::InvalidDrawing(line, col)),
+        }
+    }
+}
+
+impl FromStr for Grid {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_drawing(s)
+    }
+}
+
+/// Build a [`Grid`] from a drawing as produced by its `Display` implementation.
+///
+/// The drawing is parsed at compile time, so malformed drawings fail to compile.
+///
+/// ```
+/// use microtile_engine::{geometry::grid::Grid, grid};
+///
+/// let grid = grid!(
+///     "
+///     +-----+
+///     |     |
+///     |     |
+///     |     |
+///     |     |
+///     |x   x|
+///     +-----+
+///     "
+/// );
+///
+/// assert!(grid.is_element_set(0, 4).unwrap());
+/// ```
+#[macro_export]
+macro_rules! grid {
+    ($drawing:expr) => {{
+        use $crate::geometry::grid::Grid;
+
+        const GRID: Grid = match Grid::from_drawing($drawing) {
+            Ok(grid) => grid,
+            Err(_) => panic!("Malformed grid drawing"),
+        };
+        GRID
+    }};
+}
+
+/// # Panics
+///
+/// Panics if any index is out of range.
+impl FromIterator<(usize, usize)> for Grid {
+    fn from_iter<I: IntoIterator<Item = (usize, usize)>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::default(), |grid, (row, col)| {
+            grid.set_element(row, col)
+                .expect("Elements should be within the grid")
+        })
     }
 }
 
@@ -207,6 +774,8 @@ impl ExtGrid {
     const RIM_RAW: u64 = 0x00ff_ffff_0000_0000;
     pub const RIM: Self = Self::new(Self::RIM_RAW);
 
+    const ALL: u64 = Self::RIM_RAW | Grid::ALL as u64;
+
     const fn new(grid: u64) -> Self {
         Self(grid)
     }
@@ -263,6 +832,21 @@ impl ExtGrid {
         }
     }
 
+    /// Grid encoded in `bits`, see [`to_bits`](Self::to_bits)
+    pub fn from_bits(bits: u64) -> Result<Self, GridError> {
+        match bits & !Self::ALL {
+            0 => Ok(Self::new(bits)),
+            invalid => Err(GridError::InvalidBits(invalid)),
+        }
+    }
+
+    /// Raw encoding, the center being encoded as a [`Grid`] in the lower half and the rim in the
+    /// upper half, bottom edge first
+    #[must_use]
+    pub fn to_bits(&self) -> u64 {
+        self.0
+    }
+
     #[must_use]
     pub fn overlaps(&self, other: &Self) -> bool {
         (self.0 & other.0) != 0
@@ -308,12 +892,113 @@ impl ExtGrid {
             .ok_or(GridError::InvalidIndex(Some(row), Some(col)))
     }
 
+    /// Number of set elements, including the rim's
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Number of set elements in `row`
+    pub fn row_count(&self, row: usize) -> Result<usize, GridError> {
+        if row >= Self::NUM_ROWS {
+            return Err(GridError::InvalidIndex(Some(row), None));
+        }
+        Ok((0..Self::NUM_COLS)
+            .filter(|col| self.is_element_set(row, *col).unwrap_or(false))
+            .count())
+    }
+
+    /// Number of set elements in `col`
+    pub fn column_count(&self, col: usize) -> Result<usize, GridError> {
+        if col >= Self::NUM_COLS {
+            return Err(GridError::InvalidIndex(None, Some(col)));
+        }
+        Ok((0..Self::NUM_ROWS)
+            .filter(|row| self.is_element_set(*row, col).unwrap_or(false))
+            .count())
+    }
+
+    /// Set elements as `(row, col)`, bottom row first and left to right within each row
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..Self::NUM_ROWS)
+            .flat_map(|row| (0..Self::NUM_COLS).map(move |col| (row, col)))
+            .filter(|(row, col)| self.is_element_set(*row, *col).unwrap_or(false))
+    }
+
+    /// Rows as bit patterns, bottom row first, bit `col` being set iff element `(row, col)` is
+    /// set
+    pub fn rows(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..Self::NUM_ROWS).map(|row| {
+            (0..Self::NUM_COLS)
+                .filter(|col| self.is_element_set(row, *col).unwrap_or(false))
+                .fold(0, |pattern, col| pattern | (1 << col))
+        })
+    }
+
+    /// Columns as bit patterns, left column first, bit `row` being set iff element `(row, col)`
+    /// is set
+    pub fn columns(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..Self::NUM_COLS).map(|col| {
+            (0..Self::NUM_ROWS)
+                .filter(|row| self.is_element_set(*row, col).unwrap_or(false))
+                .fold(0, |pattern, row| pattern | (1 << row))
+        })
+    }
+
     pub fn center(self) -> Grid {
         // the center part is encoded at the lower half of `self.0`
         Grid::new((self.0 & 0xffff_ffff) as u32)
     }
 }
 
+impl ExtGrid {
+    /// Parse a drawing as produced by `Display`, see [`Grid::from_drawing`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GridError::InvalidDrawing`] locating the first malformed character.
+    pub const fn from_drawing(s: &str) -> Result<Self, GridError> {
+        let bits = match parse_drawing(s.as_bytes(), Self::NUM_ROWS, Self::NUM_COLS) {
+            Ok(bits) => bits,
+            Err((line, col)) => return Err(GridError::InvalidDrawing(line, col)),
+        };
+
+        // The drawing is row major, but `ExtGrid`'s encoding is not
+        let mut grid = 0;
+        let mut row = 0;
+        while row < Self::NUM_ROWS {
+            let mut col = 0;
+            while col < Self::NUM_COLS {
+                if bits & (1 << (row * Self::NUM_COLS + col)) != 0 {
+                    if let Some(bit) = Self::element_bit(row, col) {
+                        grid |= bit;
+                    }
+                }
+                col += 1;
+            }
+            row += 1;
+        }
+        Ok(Self::new(grid))
+    }
+}
+
+impl Display for ExtGrid {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_drawing(f, Self::NUM_ROWS, Self::NUM_COLS, |row, col| {
+            self.is_element_set(row, col)
+                .expect("Hardcoded range should be valid")
+        })
+    }
+}
+
+impl FromStr for ExtGrid {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_drawing(s)
+    }
+}
+
 impl Default for ExtGrid {
     fn default() -> Self {
         Self::new(0)
@@ -332,6 +1017,82 @@ impl From<&Grid> for ExtGrid {
     }
 }
 
+/// Implements the set operators for a grid type, `!` only flipping elements inside the grid
+macro_rules! set_ops {
+    ($grid:ty) => {
+        set_ops!($grid, BitOr, bitor, BitOrAssign, bitor_assign, union);
+        set_ops!(
+            $grid,
+            BitAnd,
+            bitand,
+            BitAndAssign,
+            bitand_assign,
+            intersect
+        );
+        set_ops!($grid, Sub, sub, SubAssign, sub_assign, subtract);
+
+        impl Not for $grid {
+            type Output = Self;
+
+            fn not(self) -> Self::Output {
+                !&self
+            }
+        }
+
+        impl Not for &$grid {
+            type Output = $grid;
+
+            fn not(self) -> Self::Output {
+                <$grid>::new(!self.0 & <$grid>::ALL)
+            }
+        }
+    };
+    ($grid:ty, $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $method:ident) => {
+        impl $op for $grid {
+            type Output = Self;
+
+            fn $fn(self, rhs: Self) -> Self::Output {
+                self.$method(&rhs)
+            }
+        }
+
+        impl $op<&$grid> for &$grid {
+            type Output = $grid;
+
+            fn $fn(self, rhs: &$grid) -> Self::Output {
+                self.$method(rhs)
+            }
+        }
+
+        impl $op_assign for $grid {
+            fn $fn_assign(&mut self, rhs: Self) {
+                *self = self.$method(&rhs);
+            }
+        }
+
+        impl $op_assign<&$grid> for $grid {
+            fn $fn_assign(&mut self, rhs: &$grid) {
+                *self = self.$method(rhs);
+            }
+        }
+    };
+}
+
+set_ops!(Grid);
+set_ops!(ExtGrid);
+
+/// # Panics
+///
+/// Panics if any index is out of range.
+impl FromIterator<(usize, usize)> for ExtGrid {
+    fn from_iter<I: IntoIterator<Item = (usize, usize)>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::default(), |grid, (row, col)| {
+            grid.set_element(row, col)
+                .expect("Elements should be within the grid")
+        })
+    }
+}
+
 impl From<[[bool; Self::NUM_COLS]; Self::NUM_ROWS]> for ExtGrid {
     fn from(value: [[bool; Self::NUM_COLS]; Self::NUM_ROWS]) -> Self {
         let mut grid = Self::default();
@@ -477,7 +1238,7 @@ where
     type Error = GridError;
 
     fn try_from(value: DisplacedTile<T>) -> Result<Self, Self::Error> {
-        Self::try_from_discrete_2d_set(&value)
+        Self::try_from(&value)
     }
 }
 
@@ -488,7 +1249,10 @@ where
     type Error = GridError;
 
     fn try_from(value: &DisplacedTile<T>) -> Result<Self, Self::Error> {
-        Self::try_from_discrete_2d_set(value)
+        match mask::mask(value.tile()) {
+            Some(mask) => mask.displace(*value.displ_x(), *value.displ_y()),
+            None => Self::try_from_discrete_2d_set(value),
+        }
     }
 }
 
@@ -566,6 +1330,140 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn metrics() {
+        let grid = Grid::from([
+            [true, false, true, true, true],
+            [true, true, false, true, true],
+            [false, true, false, true, false],
+            [false, false, false, true, false],
+            [false; Grid::NUM_COLS],
+        ]);
+
+        assert_eq!(grid.row_fill_counts(), [4, 4, 2, 1, 0]);
+        assert_eq!(grid.full_rows(), 0);
+        assert_eq!(grid.column_heights(), [2, 3, 1, 4, 2]);
+        assert_eq!(grid.max_height(), 4);
+        assert_eq!(grid.aggregate_height(), 12);
+        assert_eq!(grid.column_holes(), [0, 1, 0, 0, 0]);
+        assert_eq!(grid.holes(), 1);
+        assert_eq!(grid.bumpiness(), 1 + 2 + 3 + 2);
+        assert_eq!(grid.wells(), [1, 0, 2, 0, 2]);
+    }
+
+    #[test]
+    fn full_rows() {
+        let grid = Grid::from([
+            [true; Grid::NUM_COLS],
+            [true, true, false, true, true],
+            [true; Grid::NUM_COLS],
+            [false; Grid::NUM_COLS],
+            [false; Grid::NUM_COLS],
+        ]);
+
+        assert_eq!(grid.full_rows(), 2);
+        assert_eq!(grid.full_row_mask(), 0b101);
+        assert_eq!(Grid::R4.full_row_mask(), 0b1_0000);
+        // No false positives from elements set across row boundaries
+        assert_eq!(Grid::new(0x01ff_fffe).full_row_mask(), 0b1_1110);
+    }
+
+    #[test]
+    fn discard_rows() {
+        let grid = Grid::from([
+            [true; Grid::NUM_COLS],
+            [true, true, false, true, true],
+            [true; Grid::NUM_COLS],
+            [false, false, true, false, false],
+            [false; Grid::NUM_COLS],
+        ]);
+
+        assert_eq!(
+            grid.clone().discard_rows(grid.full_row_mask()).unwrap(),
+            Grid::from([
+                [true, true, false, true, true],
+                [false, false, true, false, false],
+                [false; Grid::NUM_COLS],
+                [false; Grid::NUM_COLS],
+                [false; Grid::NUM_COLS],
+            ])
+        );
+        assert_eq!(
+            grid.clone().discard_rows(0b100).unwrap(),
+            grid.clone().discard_and_shift(2).unwrap()
+        );
+        assert_eq!(
+            Grid::from_row_mask(0b101).unwrap(),
+            Grid::R0.union(&Grid::R2)
+        );
+        assert!(matches!(
+            grid.discard_rows(0b10_0000),
+            Err(GridError::InvalidIndex(Some(5), None))
+        ));
+    }
+
+    #[test]
+    fn drawing_round_trip() {
+        let grid = Grid::default()
+            .set_element(0, 0)
+            .unwrap()
+            .set_element(3, 4)
+            .unwrap();
+        let ext_grid = ExtGrid::RIM.set_element(2, 3).unwrap();
+
+        let mut drawing = heapless::String::<128>::new();
+        write!(drawing, "{grid}").unwrap();
+        assert_eq!(drawing.parse::<Grid>().unwrap(), grid);
+
+        drawing.clear();
+        write!(drawing, "{ext_grid}").unwrap();
+        assert_eq!(drawing.parse::<ExtGrid>().unwrap(), ext_grid);
+    }
+
+    #[test]
+    fn drawing_macro() {
+        let grid = crate::grid!(
+            "
+            +-----+
+            |     |
+            |    x|
+            |     |
+            |     |
+            |x    |
+            +-----+
+            "
+        );
+
+        assert_eq!(
+            grid,
+            Grid::default()
+                .set_element(0, 0)
+                .unwrap()
+                .set_element(3, 4)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn drawing_errors_are_located() {
+        let error = |s: &str| match s.parse::<Grid>() {
+            Err(GridError::InvalidDrawing(line, col)) => (line, col),
+            _ => panic!("Drawing should be invalid"),
+        };
+
+        assert_eq!(error("+-----+\n|  o  |\n"), (2, 4));
+        assert_eq!(error("+----+\n"), (1, 6));
+        assert_eq!(error("\n  +-----+\n  |     |\n\n"), (4, 1));
+        assert_eq!(
+            error("+-----+\n|     |\n|     |\n|     |\n|     |\n|     |\n"),
+            (7, 1)
+        );
+        assert_eq!(
+            error("+-----+\n|     |\n|     |\n|     |\n|     |\n|     |\n+-----+\nx"),
+            (8, 1)
+        );
+    }
+
     #[test]
     fn ext_center_matches_grid() -> Result<(), GridError> {
         let grid = Grid::default()
@@ -580,4 +1478,216 @@ mod tests {
         assert_eq!(grid, ext_grid.center());
         Ok(())
     }
+
+    const ARROW: Grid = crate::grid!(
+        "
+        +-----+
+        |     |
+        |  x  |
+        |   x |
+        |xxxxx|
+        |     |
+        +-----+
+        "
+    );
+
+    #[test]
+    fn shifts() {
+        assert_eq!(
+            ARROW.shift_left(2, false),
+            crate::grid!(
+                "
+                +-----+
+                |     |
+                |x    |
+                | x   |
+                |xxx  |
+                |     |
+                +-----+
+                "
+            )
+        );
+        assert_eq!(
+            ARROW.shift_right(1, true),
+            crate::grid!(
+                "
+                +-----+
+                |x    |
+                |x  x |
+                |x   x|
+                |xxxxx|
+                |x    |
+                +-----+
+                "
+            )
+        );
+        assert_eq!(
+            ARROW.shift_up(2, false),
+            crate::grid!(
+                "
+                +-----+
+                |   x |
+                |xxxxx|
+                |     |
+                |     |
+                |     |
+                +-----+
+                "
+            )
+        );
+        assert_eq!(
+            ARROW.shift_down(1, true),
+            crate::grid!(
+                "
+                +-----+
+                |xxxxx|
+                |     |
+                |  x  |
+                |   x |
+                |xxxxx|
+                +-----+
+                "
+            )
+        );
+        assert_eq!(ARROW.shift_left(7, true), Grid::new(Grid::ALL));
+        assert_eq!(ARROW.shift_down(5, false), Grid::default());
+    }
+
+    #[test]
+    fn mirrors_and_rotations() {
+        assert_eq!(
+            ARROW.mirror_horizontal(),
+            crate::grid!(
+                "
+                +-----+
+                |     |
+                |  x  |
+                | x   |
+                |xxxxx|
+                |     |
+                +-----+
+                "
+            )
+        );
+        assert_eq!(
+            ARROW.mirror_vertical(),
+            crate::grid!(
+                "
+                +-----+
+                |     |
+                |xxxxx|
+                |   x |
+                |  x  |
+                |     |
+                +-----+
+                "
+            )
+        );
+        assert_eq!(
+            ARROW.rotate_ccw(),
+            crate::grid!(
+                "
+                +-----+
+                |   x |
+                |  xx |
+                | x x |
+                |   x |
+                |   x |
+                +-----+
+                "
+            )
+        );
+        assert_eq!(ARROW.rotate_cw(), ARROW.rotate_ccw().rotate_180());
+        assert_eq!(ARROW.transpose().transpose(), ARROW);
+
+        let mut grid = ARROW;
+        for _ in 0..4 {
+            grid = grid.rotate_cw();
+        }
+        assert_eq!(grid, ARROW);
+    }
+
+    #[test]
+    fn iterators() {
+        let cells: heapless::Vec<(usize, usize), 8> = ARROW.cells().collect();
+        assert_eq!(
+            cells.as_slice(),
+            &[(1, 0), (1, 1), (1, 2), (1, 3), (1, 4), (2, 3), (3, 2)]
+        );
+        assert_eq!(ARROW.cells().collect::<Grid>(), ARROW);
+
+        assert!(ARROW.rows().eq([0, 0b1_1111, 0b1000, 0b100, 0]));
+        assert!(ARROW.columns().eq([0b10, 0b10, 0b1010, 0b110, 0b10]));
+
+        assert_eq!(ARROW.count_ones(), 7);
+        assert_eq!(ARROW.row_count(1).unwrap(), 5);
+        assert_eq!(ARROW.column_count(2).unwrap(), 2);
+        assert!(ARROW.row_count(5).is_err());
+        assert!(ARROW.column_count(5).is_err());
+    }
+
+    #[test]
+    fn ext_iterators() {
+        let grid: ExtGrid = [(0, 0), (3, 6), (3, 2)].into_iter().collect();
+
+        assert!(grid.cells().eq([(0, 0), (3, 2), (3, 6)]));
+        assert!(grid.rows().eq([1, 0, 0, 0b100_0100, 0, 0, 0]));
+        assert!(grid.columns().eq([1, 0, 0b1000, 0, 0, 0, 0b1000]));
+
+        assert_eq!(ExtGrid::RIM.count_ones(), 24);
+        assert_eq!(ExtGrid::RIM.row_count(0).unwrap(), 7);
+        assert_eq!(ExtGrid::RIM.column_count(3).unwrap(), 2);
+        assert_eq!(grid.row_count(3).unwrap(), 2);
+        assert!(grid.row_count(7).is_err());
+    }
+
+    #[test]
+    fn operators() {
+        let mut grid = ARROW.clone() | Grid::R0;
+        assert_eq!(grid, ARROW.union(&Grid::R0));
+        assert_eq!(&grid & &Grid::R1, Grid::R1);
+        assert_eq!(
+            &grid - &Grid::R1,
+            ARROW.subtract(&Grid::R1).union(&Grid::R0)
+        );
+
+        grid -= Grid::R0;
+        grid &= &ARROW;
+        assert_eq!(grid, ARROW);
+        grid |= Grid::R4;
+        assert!(grid.contains(&Grid::R4));
+
+        assert_eq!(!Grid::default(), Grid::from_bits(0x01ff_ffff).unwrap());
+        assert_eq!(!!ARROW.clone(), ARROW);
+        assert_eq!((!&ARROW).count_ones(), 25 - ARROW.count_ones());
+
+        assert_eq!(
+            !ExtGrid::default(),
+            ExtGrid::RIM | ExtGrid::from(!Grid::default())
+        );
+        assert_eq!(!ExtGrid::RIM, ExtGrid::from(!Grid::default()));
+    }
+
+    #[test]
+    fn raw_bits() {
+        assert_eq!(Grid::from_bits(ARROW.to_bits()).unwrap(), ARROW);
+        assert_eq!(Grid::from_bits(Grid::R4.to_bits()).unwrap(), Grid::R4);
+        assert!(matches!(
+            Grid::from_bits(0x0200_0001),
+            Err(GridError::InvalidBits(0x0200_0000))
+        ));
+
+        assert_eq!(
+            ExtGrid::from_bits(ExtGrid::RIM.to_bits()).unwrap(),
+            ExtGrid::RIM
+        );
+        assert!(matches!(
+            ExtGrid::from_bits(1 << 63),
+            Err(GridError::InvalidBits(invalid)) if invalid == 1 << 63
+        ));
+        assert!(matches!(
+            ExtGrid::from_bits(1 << 30),
+            Err(GridError::InvalidBits(invalid)) if invalid == 1 << 30
+        ));
+    }
 }
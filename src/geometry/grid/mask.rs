@@ -0,0 +1,246 @@
+use super::{ExtGrid, Grid, GridError};
+use crate::geometry::tile::{Angle, BasicTile, Discrete2DSet};
+
+/// Argument of [`Discrete2DSet::mask`], preventing other crates from calling or overriding it.
+pub struct Token;
+
+/// Precomputed cells of `set`, if available.
+pub(crate) fn mask<T>(set: &T) -> Option<TileMask>
+where
+    T: Discrete2DSet,
+{
+    set.mask(Token)
+}
+
+/// Cells of a [`BasicTile`] at some [`Angle`] relative to the tile's origin, precomputed at
+/// compile time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TileMask {
+    /// Cells encoded like a `Grid`, with the tile's origin at element `(1, 1)`
+    bits: u32,
+    /// Index of the tile into `MASKS`, allowing to rotate the mask further
+    tile: usize,
+    /// Index of the angle into `MASKS[tile]`, allowing to rotate the mask further
+    angle: usize,
+    /// Leftmost cell's x offset from the tile's origin
+    min_x: i32,
+    /// Rightmost cell's x offset from the tile's origin
+    max_x: i32,
+    /// Topmost cell's y offset from the tile's origin
+    min_y: i32,
+    /// Bottommost cell's y offset from the tile's origin
+    max_y: i32,
+}
+
+impl TileMask {
+    const ORIGIN: i32 = 1;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    const NUM_ROWS: i32 = Grid::NUM_ROWS as i32;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    const NUM_COLS: i32 = Grid::NUM_COLS as i32;
+
+    const MASKS: [[Self; 4]; 3] = [
+        Self::build_all(&BasicTile::Square),
+        Self::build_all(&BasicTile::Diagonal),
+        Self::build_all(&BasicTile::Line),
+    ];
+
+    const fn build_all(tile: &BasicTile) -> [Self; 4] {
+        [
+            Self::build(tile, &Angle::Zero),
+            Self::build(tile, &Angle::Ninety),
+            Self::build(tile, &Angle::OneEighty),
+            Self::build(tile, &Angle::TwoSeventy),
+        ]
+    }
+
+    const fn build(tile: &BasicTile, angle: &Angle) -> Self {
+        // has to match `BasicTile`'s implementation of `Discrete2DSet`
+        let cells: &[(i32, i32)] = match tile {
+            BasicTile::Square => &[(0, 0)],
+            BasicTile::Diagonal => &[(0, 0), (1, 1)],
+            BasicTile::Line => &[(0, 0), (0, 1)],
+        };
+
+        let mut mask = Self {
+            bits: 0,
+            tile: Self::tile_idx(tile),
+            angle: Self::angle_idx(angle),
+            min_x: i32::MAX,
+            max_x: i32::MIN,
+            min_y: i32::MAX,
+            max_y: i32::MIN,
+        };
+
+        let mut idx = 0;
+        while idx < cells.len() {
+            let (x, y) = cells[idx];
+            // counter-clockwise rotation, see `RotatedTile`'s implementation of `Discrete2DSet`
+            let (x, y) = match angle {
+                Angle::Zero => (x, y),
+                Angle::Ninety => (-y, x),
+                Angle::OneEighty => (-x, -y),
+                Angle::TwoSeventy => (y, -x),
+            };
+
+            mask.bits |= 1 << ((y + Self::ORIGIN) * Self::NUM_COLS + x + Self::ORIGIN);
+            if x < mask.min_x {
+                mask.min_x = x;
+            }
+            if x > mask.max_x {
+                mask.max_x = x;
+            }
+            if y < mask.min_y {
+                mask.min_y = y;
+            }
+            if y > mask.max_y {
+                mask.max_y = y;
+            }
+            idx += 1;
+        }
+
+        mask
+    }
+
+    const fn tile_idx(tile: &BasicTile) -> usize {
+        match tile {
+            BasicTile::Square => 0,
+            BasicTile::Diagonal => 1,
+            BasicTile::Line => 2,
+        }
+    }
+
+    const fn angle_idx(angle: &Angle) -> usize {
+        match angle {
+            Angle::Zero => 0,
+            Angle::Ninety => 1,
+            Angle::OneEighty => 2,
+            Angle::TwoSeventy => 3,
+        }
+    }
+
+    pub(crate) const fn of(tile: &BasicTile, angle: &Angle) -> Self {
+        Self::MASKS[Self::tile_idx(tile)][Self::angle_idx(angle)]
+    }
+
+    /// Mask of the same tile, rotated by `angle` on top of its current angle
+    pub(crate) const fn rotated(self, angle: &Angle) -> Self {
+        Self::MASKS[self.tile][(self.angle + Self::angle_idx(angle)) % 4]
+    }
+
+    /// Cells of the tile with its origin displaced to `(x, y)` in `ExtGrid` coordinates.
+    ///
+    /// Cells outside the `ExtGrid` are dropped, like [`ExtGrid`]'s `TryFrom` impls do.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GridError::EmptyIntersection`] if no cell lies within the `ExtGrid`.
+    pub(crate) fn displace(self, x: i32, y: i32) -> Result<ExtGrid, GridError> {
+        // origin in `Grid` coordinates
+        let (center_x, center_y) = (x - 1, y - 1);
+
+        if center_x + self.min_x >= 0
+            && center_x + self.max_x < Self::NUM_COLS
+            && center_y + self.min_y >= 0
+            && center_y + self.max_y < Self::NUM_ROWS
+        {
+            let shift = (center_y - Self::ORIGIN) * Self::NUM_COLS + (center_x - Self::ORIGIN);
+            return Ok(Grid::new(if shift >= 0 {
+                self.bits << shift
+            } else {
+                self.bits >> -shift
+            })
+            .into());
+        }
+
+        // Some cells lie on the rim or beyond, so set the tile's few cells one by one
+        let mut grid = 0;
+        let mut bits = self.bits;
+        while bits != 0 {
+            #[allow(clippy::cast_possible_wrap)]
+            let idx = bits.trailing_zeros() as i32;
+            bits &= bits - 1;
+
+            let row = y + idx / Self::NUM_COLS - Self::ORIGIN;
+            let col = x + idx % Self::NUM_COLS - Self::ORIGIN;
+            if let (Ok(row), Ok(col)) = (usize::try_from(row), usize::try_from(col)) {
+                if let Some(bit) = ExtGrid::element_bit(row, col) {
+                    grid |= bit;
+                }
+            }
+        }
+
+        match grid {
+            0 => Err(GridError::EmptyIntersection),
+            grid => Ok(ExtGrid::new(grid)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::tile::RotatedTile;
+
+    #[test]
+    fn tile_masks_match_discrete_2d_set() {
+        use crate::geometry::tile::{Displacee, Rotatee};
+
+        for tile in [BasicTile::Square, BasicTile::Diagonal, BasicTile::Line] {
+            let mut rotated = RotatedTile::new(tile.clone());
+            for _ in 0..4 {
+                for x in -2..=8 {
+                    for y in -2..=8 {
+                        let displaced = rotated.clone().displace_by(x, y);
+                        let generic = ExtGrid::try_from_discrete_2d_set(&displaced);
+
+                        match ExtGrid::try_from(&displaced) {
+                            Ok(grid) => assert_eq!(Some(grid), generic.ok()),
+                            Err(_) => assert!(generic.is_err()),
+                        }
+                    }
+                }
+                rotated = rotated.rotate_ccw();
+            }
+        }
+    }
+
+    #[test]
+    fn tile_masks_rotate() {
+        let mask = TileMask::of(&BasicTile::Diagonal, &Angle::Ninety);
+
+        assert_eq!(
+            mask.rotated(&Angle::OneEighty),
+            TileMask::of(&BasicTile::Diagonal, &Angle::TwoSeventy)
+        );
+        assert_eq!(
+            mask.rotated(&Angle::TwoSeventy),
+            TileMask::of(&BasicTile::Diagonal, &Angle::Zero)
+        );
+    }
+
+    #[test]
+    fn tile_mask_leaving_center() -> Result<(), GridError> {
+        let mask = TileMask::of(&BasicTile::Line, &Angle::Ninety);
+
+        // A horizontal line extends to the left of its origin
+        assert_eq!(
+            mask.displace(1, 1).ok(),
+            ExtGrid::default().set_element(1, 0)?.set_element(1, 1).ok()
+        );
+        assert_eq!(
+            mask.displace(2, 1).ok(),
+            Some(Grid::default().set_element(0, 0)?.set_element(0, 1)?.into())
+        );
+        // Cells beyond the rim are dropped
+        assert_eq!(
+            mask.displace(0, 6).ok(),
+            ExtGrid::default().set_element(6, 0).ok()
+        );
+        assert!(matches!(
+            mask.displace(-1, 3),
+            Err(GridError::EmptyIntersection)
+        ));
+        Ok(())
+    }
+}
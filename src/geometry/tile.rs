@@ -1,4 +1,6 @@
-#[derive(Clone, Debug)]
+use super::grid::{TileMask, Token};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Angle {
     /// 0° in counter-clockwise order
     Zero,
@@ -10,7 +12,7 @@ pub enum Angle {
     TwoSeventy,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum BasicTile {
     /// 1x1 square
     Square,
@@ -30,6 +32,16 @@ impl<T> RotatedTile<T> {
     pub fn new(t: T) -> Self {
         Self { t, a: Angle::Zero }
     }
+
+    /// The tile being rotated
+    pub fn tile(&self) -> &T {
+        &self.t
+    }
+
+    /// Counter-clockwise rotation applied to the tile
+    pub fn angle(&self) -> &Angle {
+        &self.a
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -48,6 +60,11 @@ impl<T> DisplacedTile<T> {
         }
     }
 
+    /// The tile being displaced
+    pub fn tile(&self) -> &T {
+        &self.t
+    }
+
     pub fn displ_x(&self) -> &i32 {
         &self.displ_x
     }
@@ -60,6 +77,16 @@ impl<T> DisplacedTile<T> {
 pub trait Discrete2DSet {
     fn contains(&self, x: i32, y: i32) -> bool;
     fn is_empty(&self) -> bool;
+
+    /// Precomputed cells of the set, if available.
+    ///
+    /// Sets providing a mask are rasterized by shifting the mask's bits instead of querying
+    /// [`Discrete2DSet::contains`] for every element of the grid. Only implemented within this
+    /// crate, as [`Token`] cannot be named elsewhere.
+    #[doc(hidden)]
+    fn mask(&self, _: Token) -> Option<TileMask> {
+        None
+    }
 }
 
 impl Discrete2DSet for BasicTile {
@@ -74,6 +101,10 @@ impl Discrete2DSet for BasicTile {
     fn is_empty(&self) -> bool {
         false
     }
+
+    fn mask(&self, _: Token) -> Option<TileMask> {
+        Some(TileMask::of(self, &Angle::Zero))
+    }
 }
 
 impl<T> Discrete2DSet for RotatedTile<T>
@@ -98,6 +129,10 @@ where
     fn is_empty(&self) -> bool {
         self.t.is_empty()
     }
+
+    fn mask(&self, token: Token) -> Option<TileMask> {
+        self.t.mask(token).map(|mask| mask.rotated(&self.a))
+    }
 }
 
 impl<T> Discrete2DSet for DisplacedTile<T>
@@ -111,6 +146,9 @@ where
     fn is_empty(&self) -> bool {
         self.t.is_empty()
     }
+
+    // No `mask`: masks are relative to the tile's origin, so the displacement is applied when
+    // rasterizing, see `ExtGrid`'s `TryFrom<&DisplacedTile<T>>`.
 }
 
 pub trait Rotatee {
@@ -8,5 +8,7 @@
     clippy::missing_panics_doc
 )]
 
+pub mod ai;
+pub mod display;
 pub mod gameplay;
 pub mod geometry;
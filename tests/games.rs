@@ -1,12 +1,20 @@
 use anyhow::{bail, Ok, Result};
 use either::Either;
 use microtile_engine::{
+    display::sink::{Frame, RecordingSink, SinkObserver},
     gameplay::{
-        board::{BOARD_COLS, BOARD_ROWS},
-        game::{Game, GameError, NoopObserver, Over, ProcessRows, TileFloating, TileNeeded},
-        raster::{Active, Passive, Rasterization},
+        board::{Board, BOARD_COLS, BOARD_ROWS},
+        game::{
+            Game, GameError, NoopObserver, Over, ProcessRows, TileFloating, TileNeeded, PAUSE_ICON,
+        },
+        placement::Move,
+        raster::{Active, Passive, Rasterization, RasterizationExt},
+        spawn::{Column, SpawnPolicy},
+    },
+    geometry::{
+        grid::Grid,
+        tile::{Angle, BasicTile, Displacee, Rotatee},
     },
-    geometry::{grid::Grid, tile::BasicTile},
 };
 
 fn place_tile_continue(
@@ -684,3 +692,220 @@ fn game_one() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn follow_path() -> Result<()> {
+    let game = Game::default();
+    let mut game = place_tile_continue(game, BasicTile::Line)?;
+
+    // Horizontal line in the bottom left corner
+    let target = BasicTile::Line.rotate_ccw().displace_by(2, 1);
+    let Some(path) = game.path_to(&target) else {
+        bail!("Target should be reachable");
+    };
+
+    for m in path {
+        match m {
+            Move::Left => game.move_tile_left(),
+            Move::Right => game.move_tile_right(),
+            Move::Rotate => game.rotate_tile(),
+            Move::Descend => {
+                game = descend_tile_no_processing(game)?;
+                continue;
+            }
+        }
+        .map_err(|_| anyhow::anyhow!("Move along path should be valid"))?;
+    }
+
+    let game = descend_tile_processing(game)?;
+    let active = [
+        [true, true, false, false, false],
+        [false; BOARD_COLS],
+        [false; BOARD_COLS],
+        [false; BOARD_COLS],
+        [false; BOARD_COLS],
+    ];
+    check_snapshots(&game, &active, &[[false; BOARD_COLS]; BOARD_ROWS]);
+
+    Ok(())
+}
+
+#[test]
+fn prepopulated_board() -> Result<()> {
+    let grid = Grid::from([
+        [true, true, true, true, false],
+        [true, false, true, true, false],
+        [false; BOARD_COLS],
+        [false; BOARD_COLS],
+        [false; BOARD_COLS],
+    ]);
+    let Some(board) = Board::from_grid(grid).ok() else {
+        bail!("Board should be valid");
+    };
+    let game = Game::with_board(board);
+
+    let mut game = place_tile_continue(game, BasicTile::Line)?;
+    move_tile_to(&mut game, 4)?;
+    let game = push_tile_down(game, 3)?;
+    let active = [
+        [true; BOARD_COLS],
+        [false; BOARD_COLS],
+        [false; BOARD_COLS],
+        [false; BOARD_COLS],
+        [false; BOARD_COLS],
+    ];
+    let passive = [
+        [false; BOARD_COLS],
+        [true, false, true, true, true],
+        [false; BOARD_COLS],
+        [false; BOARD_COLS],
+        [false; BOARD_COLS],
+    ];
+    check_snapshots(&game, &active, &passive);
+    let _ = process_rows(game, 2)?;
+
+    Ok(())
+}
+
+#[test]
+fn pause_and_resume() -> Result<()> {
+    let game = Game::<_, NoopObserver>::new();
+    let mut game = place_tile_continue(game, BasicTile::Square)?;
+    move_tile_to(&mut game, 4)?;
+    rotate_tile_valid(&mut game)?;
+    let expected = <Game<TileFloating, _> as RasterizationExt<Active>>::rasterize(&game);
+
+    let paused = game.pause();
+    assert_eq!(
+        <Game<_, _> as RasterizationExt<Active>>::rasterize(&paused),
+        PAUSE_ICON
+    );
+    assert!(<Game<_, _> as RasterizationExt<Passive>>::rasterize(&paused).is_empty());
+
+    // The floating tile is restored as is
+    let mut game = paused.resume();
+    ensure_tile_column(&mut game, 4)?;
+    assert_eq!(
+        <Game<TileFloating, _> as RasterizationExt<Active>>::rasterize(&game),
+        expected
+    );
+
+    // Games can be paused while processing rows as well
+    let mut game = push_tile_down(game, 4)?;
+    let game = loop {
+        game = match game.pause().resume().process_row() {
+            Either::Left(game) => game,
+            Either::Right(game) => break game.pause().resume(),
+        }
+    };
+    let _ = place_tile_continue(game, BasicTile::Line)?;
+
+    Ok(())
+}
+
+#[test]
+fn spawn_policy() -> Result<()> {
+    let game = Game::<_, NoopObserver>::new().with_spawn_policy(SpawnPolicy::new(Column::Fixed(0)));
+
+    let Either::Left(mut game) = game.place_tile(BasicTile::Line) else {
+        bail!("Game should not have ended by placing this tile");
+    };
+    ensure_tile_column(&mut game, 0)?;
+
+    // The policy is kept across tiles
+    let game = process_rows(push_tile_down(game, 3)?, 2)?;
+    let Either::Left(mut game) = game.place_tile(BasicTile::Square) else {
+        bail!("Game should not have ended by placing this tile");
+    };
+    ensure_tile_column(&mut game, 0)
+}
+
+#[test]
+fn move_queries() -> Result<()> {
+    let game = Game::<_, NoopObserver>::new().with_spawn_policy(SpawnPolicy::new(Column::Fixed(0)));
+    let Either::Left(mut game) = game.place_tile(BasicTile::Line) else {
+        bail!("Game should not have ended by placing this tile");
+    };
+
+    // A vertical line at the left edge can neither move left nor rotate into a horizontal line
+    assert!(!game.can_move_left());
+    assert!(!game.can_rotate());
+    assert!(matches!(
+        game.check_move(Move::Rotate),
+        Err(GameError::InvalidMove)
+    ));
+    assert!(game.can_move_right());
+    assert!(game.can_descend());
+
+    let Result::Ok(candidate) = game.check_move(Move::Right) else {
+        bail!("Moving tile right should be valid");
+    };
+    assert_eq!(*candidate.displ_x(), 2);
+    ensure_tile_column(&mut game, 0)?;
+
+    // Queries predict the result of the actual move
+    if game.move_tile_right().is_err() {
+        bail!("Moving tile right should be valid");
+    }
+    assert_eq!(
+        game.check_move(Move::Left).ok().map(|tile| *tile.displ_x()),
+        Some(1)
+    );
+    assert!(game.can_rotate());
+    ensure_tile_column(&mut game, 1)?;
+
+    Ok(())
+}
+
+#[test]
+fn tile_introspection() -> Result<()> {
+    let game = Game::<_, NoopObserver>::new();
+    let mut game = place_tile_continue(game, BasicTile::Diagonal)?;
+
+    assert_eq!(game.tile_kind(), &BasicTile::Diagonal);
+    assert_eq!(game.tile_angle(), &Angle::Zero);
+    assert_eq!(game.tile_row(), 3);
+    assert!(game.board().is_position_valid(game.tile()));
+
+    rotate_tile_valid(&mut game)?;
+    assert_eq!(game.tile_angle(), &Angle::Ninety);
+    assert_eq!(game.tile().tile().angle(), game.tile_angle());
+    assert_eq!(game.tile().tile().tile(), &BasicTile::Diagonal);
+
+    let Either::Left(game) = game.descend_tile() else {
+        bail!("Descending tile should not freeze it");
+    };
+    assert_eq!(game.tile_row(), 2);
+    assert_eq!(i32::from(game.tile_row()) + 1, *game.tile().displ_y());
+
+    Ok(())
+}
+
+#[test]
+fn display_sink() -> Result<()> {
+    let sink = RecordingSink::<8>::new();
+    let mut game = Game::new();
+    if game.set_observer(SinkObserver::new(&sink)).is_err() {
+        bail!("Fresh game should not have an observer yet");
+    }
+
+    let Either::Left(mut game) = game.place_tile(BasicTile::Square) else {
+        bail!("Game should not have ended by placing this tile");
+    };
+    if game.move_tile_left().is_err() {
+        bail!("Moving tile should be valid");
+    }
+
+    let frames = sink.frames();
+    assert_eq!(frames.len(), 2);
+    assert_eq!(
+        sink.last(),
+        Some(Frame::new(
+            <Game<TileFloating, _> as RasterizationExt<Active>>::rasterize(&game),
+            <Game<TileFloating, _> as RasterizationExt<Passive>>::rasterize(&game),
+        ))
+    );
+    assert_ne!(frames[0], frames[1]);
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+//! Tests loading an embedded level pack.
+
+use anyhow::{anyhow, Result};
+use microtile_engine::gameplay::{
+    level::parse_pack,
+    puzzle::{Goal, Puzzle},
+};
+
+const PACK: &str = include_str!("levels.txt");
+
+#[test]
+fn embedded_pack() -> Result<()> {
+    let mut goals = Vec::new();
+    let mut pars = Vec::new();
+
+    for (idx, level) in parse_pack(PACK).enumerate() {
+        let level = level.map_err(|e| anyhow!("Level {idx} failed to parse: {e:?}"))?;
+        let puzzle =
+            Puzzle::try_from(level).map_err(|e| anyhow!("Level {idx} is invalid: {e:?}"))?;
+        goals.push(puzzle.goal().clone());
+        pars.push(puzzle.par());
+    }
+
+    assert_eq!(goals.len(), 3);
+    assert_eq!(
+        goals,
+        [Goal::ClearRows(1), Goal::EmptyBoard, Goal::Survive(3)]
+    );
+    assert_eq!(pars, [Some(4), None, None]);
+    Ok(())
+}